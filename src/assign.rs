@@ -0,0 +1,199 @@
+/*!
+Consistent colors for arbitrary keys such as usernames, hostnames and log sources.
+
+A [ColorAssigner] hashes a key into a list of colors. The hash (64 bit FNV-1a) is stable across
+runs, platforms and versions, so a key is given the same color as long as the list doesn't
+change:
+```rust
+use antsee::{assign::ColorAssigner, contrast::ContrastTarget, Ansi, CssColors, Style};
+
+let assigner = ColorAssigner::from_library(&[
+    CssColors::Tomato,
+    CssColors::Gold,
+    CssColors::MediumSeaGreen,
+    CssColors::RoyalBlue,
+])
+.background(Style::default().bg(Ansi::Black), ContrastTarget::Wcag(4.5));
+let color = assigner.assign("alice").unwrap();
+assert_eq!(assigner.assign("alice"), Some(color));
+```
+*/
+
+use std::str::FromStr;
+
+use crate::{
+    color::{libraries::ColorLibrary, Color, Rgb},
+    contrast::{AnsiPalette, ContrastTarget},
+    generate::Gamut,
+    style::Style,
+    theme::{Palette, PaletteError},
+};
+
+///Hash a key with 64 bit FNV-1a
+fn fnv1a(key: &str) -> u64 {
+    key.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+///Assigns colors to keys by hashing them. See the [module documentation](self).
+#[derive(Clone, Debug)]
+pub struct ColorAssigner {
+    colors: Vec<Color>,
+    background: Option<(Style, ContrastTarget)>,
+    ansi_palette: AnsiPalette,
+    palette: Palette,
+}
+
+impl ColorAssigner {
+    ///Create an assigner for a list of colors
+    pub fn new<C: Into<Color>, I: IntoIterator<Item = C>>(colors: I) -> Self {
+        Self {
+            colors: colors.into_iter().map(Into::into).collect(),
+            background: None,
+            ansi_palette: AnsiPalette::default(),
+            palette: Palette::default(),
+        }
+    }
+
+    ///Create an assigner for the entries of a [Palette], in name order. The assigned colors are
+    ///references to their entry (e.g. `$accent`), so they serialize as the reference. Entries are
+    ///resolved against the palette for the contrast check.
+    pub fn from_palette(palette: &Palette) -> Result<Self, PaletteError> {
+        palette.resolve_entries()?;
+        let colors = palette
+            .colors()
+            .map(|(name, _)| {
+                Rgb::from_str(&format!("${}", name))
+                    .map_err(|_| PaletteError::Undefined(name.to_string()))
+            })
+            .collect::<Result<Vec<Rgb>, PaletteError>>()?;
+        let mut assigner = Self::new(colors);
+        assigner.palette = palette.clone();
+        Ok(assigner)
+    }
+
+    ///Create an assigner for colors from a [ColorLibrary]. Colors have their library name (e.g.
+    ///`css(tomato)`) as their source.
+    pub fn from_library<C: ColorLibrary + Copy>(colors: &[C]) -> Self {
+        Self::new(colors.iter().map(|color| Rgb::from(*color)))
+    }
+
+    ///Create an assigner for `count` distinct colors generated from a color with
+    ///[Rgb::categorical]
+    pub fn categorical(seed: &Rgb, count: usize, gamut: Gamut) -> Self {
+        Self::new(seed.categorical(count, gamut))
+    }
+
+    ///Only assign colors which meet a contrast target as the foreground of a style. The style
+    ///normally only sets a background.
+    pub fn background(mut self, style: Style, target: ContrastTarget) -> Self {
+        self.background = Some((style, target));
+        self
+    }
+
+    ///Set the palette terminal colors are resolved with for the contrast check
+    pub fn ansi_palette(mut self, palette: AnsiPalette) -> Self {
+        self.ansi_palette = palette;
+        self
+    }
+
+    ///Get every color of the assigner
+    pub fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+
+    ///Get the colors which can be assigned, which are the colors meeting the contrast target if
+    ///a background is set
+    pub fn candidates(&self) -> Vec<&Color> {
+        self.colors
+            .iter()
+            .filter(|color| match &self.background {
+                None => true,
+                Some((background, target)) => {
+                    let fg = self.palette.resolve(color).ok();
+                    let style = Style {
+                        fg: fg.or_else(|| Some((*color).clone())),
+                        ..background.clone()
+                    };
+                    target.is_met(&style.contrast_with(&self.ansi_palette))
+                }
+            })
+            .collect()
+    }
+
+    ///Get the color of a key. Returns [None] if there are no candidates.
+    pub fn assign(&self, key: &str) -> Option<Color> {
+        let candidates = self.candidates();
+        if candidates.is_empty() {
+            return None;
+        }
+        let index = fnv1a(key) % candidates.len() as u64;
+        Some(candidates[index as usize].clone())
+    }
+}
+
+#[cfg(test)]
+mod assign_tests {
+    use super::*;
+    use crate::{
+        color::{Ansi, Fixed},
+        CssColors,
+    };
+
+    #[test]
+    fn test_fnv1a() {
+        assert_eq!(fnv1a(""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a("a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(fnv1a("foobar"), 0x85944171f73967e8);
+    }
+
+    #[test]
+    fn test_assign() {
+        let assigner = ColorAssigner::new([
+            Ansi::Red,
+            Ansi::Green,
+            Ansi::Yellow,
+            Ansi::Blue,
+            Ansi::Magenta,
+        ]);
+        //fnv1a("a") % 5 == 1
+        assert_eq!(assigner.assign("a"), Some(Ansi::Green.into()));
+        assert_eq!(assigner.assign("alice"), assigner.assign("alice"));
+        let keys = ["alice", "bob", "carol", "dave", "erin", "frank", "grace"];
+        let assigned: Vec<_> = keys.iter().filter_map(|k| assigner.assign(k)).collect();
+        assert!(assigned.iter().any(|c| *c != assigned[0]));
+        assert_eq!(ColorAssigner::new(Vec::<Color>::new()).assign("a"), None);
+    }
+
+    #[test]
+    fn test_assign_contrast() {
+        let assigner = ColorAssigner::new([Ansi::Blue, Ansi::BrightYellow])
+            .background(Style::default().bg(Ansi::Black), ContrastTarget::Wcag(4.5));
+        assert_eq!(assigner.candidates(), [&Color::from(Ansi::BrightYellow)]);
+        for key in ["alice", "bob", "carol"] {
+            assert_eq!(assigner.assign(key), Some(Ansi::BrightYellow.into()));
+        }
+        let impossible = ColorAssigner::new([Ansi::Black])
+            .background(Style::default().bg(Ansi::Black), ContrastTarget::Wcag(4.5));
+        assert_eq!(impossible.assign("alice"), None);
+    }
+
+    #[test]
+    fn test_assign_sources() {
+        let palette = Palette::new()
+            .color("accent", Fixed::new().code(208))
+            .color("muted", Ansi::Blue);
+        let assigner = ColorAssigner::from_palette(&palette).unwrap();
+        assert_eq!(assigner.colors().len(), 2);
+        assert_eq!(assigner.colors()[0].palette_reference(), Some("accent"));
+        assert_eq!(assigner.colors()[1].to_string(), "$muted");
+        let assigner =
+            assigner.background(Style::default().bg(Ansi::Black), ContrastTarget::Wcag(4.5));
+        assert_eq!(assigner.candidates(), [&assigner.colors()[0]]);
+
+        let assigner = ColorAssigner::from_library(&[CssColors::Tomato]);
+        let color = assigner.assign("alice").unwrap();
+        assert_eq!(color.to_string(), "css(tomato)");
+    }
+}
@@ -6,18 +6,32 @@
 
 /** ansi provides a representation of the basic ANSI colors as an enum*/
 mod ansi;
+/** expression evaluates color expressions such as `mix(css(navy), #ffffff, 30%)` */
+pub(crate) mod expression;
 /** fixed provides a representation of the ANSI256 palette, including parsing from [XtermColors] */
 mod fixed;
 /** rgb provides a representation of RGB colors, including parsing from [CssColors], [XtermColors],
 * and hexadecimals */
 mod rgb;
+/** space provides conversions between sRGB and perceptual color spaces */
+pub(crate) mod space;
 
 ///libraries is the module containing the CSS and Xterm color libraries
 pub mod libraries;
+
+///colormap samples colormaps for data visualization, such as `viridis(0.35)`
+pub mod colormap;
+
+///distance measures the perceptual difference between colors
+pub mod distance;
+
+///registry holds user defined color libraries, such as brand colors referenced as
+///`brand(primary)`
+pub mod registry;
 use std::fmt::Display;
 
 #[doc(inline)]
-pub use {ansi::Ansi, fixed::Fixed, rgb::Rgb};
+pub use {ansi::Ansi, distance::DistanceMetric, fixed::Fixed, rgb::Rgb};
 
 ///This enum defines various errors that may be encountered when parsing a colour from a string
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -69,6 +83,50 @@ impl Color {
         }
         None
     }
+
+    ///Get the name of the palette entry the color references (e.g. `accent` for `$accent`), if
+    ///its active source is a palette reference
+    pub fn palette_reference(&self) -> Option<&str> {
+        match self {
+            Color::Ansi(_) => None,
+            Color::Fixed(fixed) => fixed.palette_reference(),
+            Color::Rgb(rgb) => rgb.palette_reference(),
+        }
+    }
+
+    ///Get the active source of the color, if it has one
+    pub(crate) fn active_source(&self) -> Option<&str> {
+        match self {
+            Color::Ansi(_) => None,
+            Color::Fixed(fixed) => fixed.active_source(),
+            Color::Rgb(rgb) => rgb.active_source(),
+        }
+    }
+
+    ///Get the color expression the color was parsed from, if it has one
+    pub(crate) fn expression(&self) -> Option<&str> {
+        self.active_source()
+            .filter(|s| expression::is_expression(s))
+    }
+
+    ///Get the [Rgb] value of the color, using the standard xterm palette for [Ansi] and [Fixed].
+    ///Returns [None] for [Ansi::Default] and unresolved colors (see [Rgb::is_resolved])
+    pub fn to_rgb(&self) -> Option<Rgb> {
+        match self {
+            Color::Ansi(ansi) => ansi.to_rgb(),
+            Color::Fixed(fixed) => Some(fixed.to_rgb()),
+            Color::Rgb(rgb) => rgb.is_resolved().then(|| rgb.clone()),
+        }
+    }
+
+    ///Returns whether the color has a value. Only [Rgb] colors can be unresolved, see
+    ///[Rgb::is_resolved].
+    pub fn is_resolved(&self) -> bool {
+        match self {
+            Color::Rgb(rgb) => rgb.is_resolved(),
+            _ => true,
+        }
+    }
 }
 
 impl From<Ansi> for Color {
@@ -120,6 +178,18 @@ pub trait ColorSource {
     fn source_internal(&mut self);
 }
 
+///Extract the entry name from a palette reference (`$name` or `palette(name)`)
+fn parse_reference(s: &str) -> Option<&str> {
+    let name = s
+        .strip_prefix('$')
+        .or_else(|| s.strip_prefix("palette(")?.strip_suffix(')'))?;
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'));
+    valid.then_some(name)
+}
+
 #[derive(Clone, Copy, PartialEq, Debug)]
 enum Source<S> {
     Active(S),
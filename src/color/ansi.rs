@@ -1,6 +1,9 @@
 use std::str::FromStr;
 
-use super::{ColorFromStrError, ColorValue};
+use super::{
+    libraries::{ColorLibrary, XtermColors},
+    ColorFromStrError, ColorValue, Rgb,
+};
 
 ///ANSI16 colour values
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
@@ -46,6 +49,75 @@ pub enum Ansi {
     LightGray,
 }
 
+impl Ansi {
+    ///Get the palette index of the color (0-15). [Ansi::Default] has no index.
+    pub fn index(&self) -> Option<u8> {
+        match self {
+            Self::Default => None,
+            Self::Black => Some(0),
+            Self::Red => Some(1),
+            Self::Green => Some(2),
+            Self::Yellow => Some(3),
+            Self::Blue => Some(4),
+            Self::Magenta => Some(5),
+            Self::Cyan => Some(6),
+            Self::White => Some(7),
+            Self::DarkGray => Some(8),
+            Self::BrightRed => Some(9),
+            Self::BrightGreen => Some(10),
+            Self::BrightYellow => Some(11),
+            Self::BrightBlue => Some(12),
+            Self::BrightMagenta => Some(13),
+            Self::BrightCyan => Some(14),
+            Self::LightGray => Some(15),
+        }
+    }
+
+    ///Get the SGR code which sets this color as the foreground (e.g. `31` for [Ansi::Red])
+    pub fn foreground_code(&self) -> u8 {
+        match self.index() {
+            None => 39,
+            Some(i) if i < 8 => 30 + i,
+            Some(i) => 82 + i,
+        }
+    }
+
+    ///Get the SGR code which sets this color as the background (e.g. `41` for [Ansi::Red])
+    pub fn background_code(&self) -> u8 {
+        self.foreground_code() + 10
+    }
+
+    ///Get the color at a palette index (0-15)
+    pub fn from_index(index: u8) -> Option<Self> {
+        let variants = [
+            Self::Black,
+            Self::Red,
+            Self::Green,
+            Self::Yellow,
+            Self::Blue,
+            Self::Magenta,
+            Self::Cyan,
+            Self::White,
+            Self::DarkGray,
+            Self::BrightRed,
+            Self::BrightGreen,
+            Self::BrightYellow,
+            Self::BrightBlue,
+            Self::BrightMagenta,
+            Self::BrightCyan,
+            Self::LightGray,
+        ];
+        variants.get(index as usize).copied()
+    }
+
+    ///Get the [Rgb] value of the color in the standard xterm palette. [Ansi::Default] has no
+    ///value as it depends on the terminal.
+    pub fn to_rgb(&self) -> Option<Rgb> {
+        self.index()
+            .map(|i| Rgb::new().rgb(XtermColors::get_ansi256(i).rgb()))
+    }
+}
+
 impl ColorValue for Ansi {}
 
 impl FromStr for Ansi {
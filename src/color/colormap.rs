@@ -0,0 +1,311 @@
+use std::fmt::Display;
+
+use super::{expression::number, ColorFromStrError, ColorSource, Rgb};
+
+///A colormap for visualizing data, sampled with a position from 0 to 1.
+///
+///Samples can be parsed as an [Rgb] with the name of the map as the wrapper, and keep the sample
+///as their source:
+///```rust
+///use std::str::FromStr;
+///use antsee::{color::colormap::Colormap, Rgb};
+///
+///let rgb = Rgb::from_str("viridis(0.35)").unwrap();
+///assert_eq!(rgb, Colormap::Viridis.sample(0.35));
+///assert_eq!(rgb.to_string(), "viridis(0.35)");
+///```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Colormap {
+    ///Perceptually uniform, from dark blue through green to yellow
+    Viridis,
+    ///Perceptually uniform, from black through purple and orange to pale yellow
+    Magma,
+    ///Perceptually uniform, from black through purple and orange to bright yellow
+    Inferno,
+    ///Perceptually uniform, from dark blue through purple and orange to yellow
+    Plasma,
+    ///Perceptually uniform and readable with color vision deficiencies, from dark blue to yellow
+    Cividis,
+    ///A rainbow map with smooth lightness changes, from dark blue through green to dark red
+    Turbo,
+    ///Diverging, from blue through light gray to red
+    Coolwarm,
+    ///Diverging, from dark red through white to dark blue
+    RdBu,
+    ///Diverging, from dark red through yellow to purple
+    Spectral,
+}
+
+///Polynomial fits of the matplotlib colormaps by Matt Zucker, with coefficients from the constant
+///term up
+const VIRIDIS: [[f64; 3]; 7] = [
+    [0.2777273272234177, 0.005407344544966578, 0.3340998053353061],
+    [0.1050930431085774, 1.404613529898575, 1.384590162594685],
+    [-0.3308618287255563, 0.214847559468213, 0.09509516302823659],
+    [-4.634230498983486, -5.799100973351585, -19.33244095627987],
+    [6.228269936347081, 14.17993336680509, 56.69055260068105],
+    [4.776384997670288, -13.74514537774601, -65.35303263337234],
+    [-5.435455855934631, 4.645852612178535, 26.3124352495832],
+];
+
+const PLASMA: [[f64; 3]; 7] = [
+    [0.05873234392399702, 0.02333670892565664, 0.5433401826748754],
+    [2.176514634195958, 0.2383834171260182, 0.7539604599784036],
+    [-2.689460476458034, -7.455851135738909, 3.110799939717086],
+    [6.130348345893603, 42.3461881477227, -28.51885465332158],
+    [-11.10743619062271, -82.66631109428045, 60.13984767418263],
+    [10.02306557647065, 71.4136177009535, -54.07218655560067],
+    [-3.658713842777788, -22.93153465461149, 18.19190778539828],
+];
+
+const MAGMA: [[f64; 3]; 7] = [
+    [
+        -0.002136485053939582,
+        -0.000749655052795221,
+        -0.005386127855323933,
+    ],
+    [0.2516605407371642, 0.6775232436837668, 2.494026599312351],
+    [8.353717279216625, -3.577719514958484, 0.3144679030132573],
+    [-27.66873308576866, 14.26473078096533, -13.64921318813922],
+    [52.17613981234068, -27.94360607168351, 12.94416944238394],
+    [-50.76852536473588, 29.04658282127291, 4.23415299384598],
+    [18.65570506591883, -11.48977351997711, -5.601961508734096],
+];
+
+const INFERNO: [[f64; 3]; 7] = [
+    [
+        0.0002189403691192265,
+        0.001651004631001012,
+        -0.01948089843709184,
+    ],
+    [0.1065134194856116, 0.5639564367884091, 3.932712388889277],
+    [11.60249308247187, -3.972853965665698, -15.9423941062914],
+    [-41.70399613139459, 17.43639888205313, 44.35414519872813],
+    [77.162935699427, -33.40235894210092, -81.80730925738993],
+    [-71.31942824499214, 32.62606426397723, 73.20951985803202],
+    [25.13112622477341, -12.24266895238567, -23.07032500287172],
+];
+
+///The polynomial approximation of Turbo by Anton Mikhailov
+const TURBO: [[f64; 3]; 6] = [
+    [0.13572138, 0.09140261, 0.1066733],
+    [4.6153926, 2.19418839, 12.64194608],
+    [-42.66032258, 4.84296658, -60.58204836],
+    [132.13108234, -14.18503333, 110.36276771],
+    [-152.94239396, 4.27729857, -89.90310912],
+    [59.28637943, 2.82956604, 27.34824973],
+];
+
+///Evenly spaced stops, interpolated in sRGB
+const CIVIDIS: [[u8; 3]; 10] = [
+    [0, 34, 78],
+    [18, 53, 112],
+    [59, 73, 108],
+    [87, 93, 109],
+    [112, 113, 115],
+    [138, 134, 120],
+    [165, 156, 116],
+    [195, 179, 105],
+    [225, 204, 85],
+    [254, 232, 56],
+];
+
+///Kenneth Moreland's smooth cool to warm map
+const COOLWARM: [[u8; 3]; 9] = [
+    [59, 76, 192],
+    [98, 130, 234],
+    [141, 176, 254],
+    [184, 208, 249],
+    [221, 221, 221],
+    [245, 196, 173],
+    [244, 154, 123],
+    [222, 96, 77],
+    [180, 4, 38],
+];
+
+///The 11 class ColorBrewer RdBu scheme
+const RDBU: [[u8; 3]; 11] = [
+    [103, 0, 31],
+    [178, 24, 43],
+    [214, 96, 77],
+    [244, 165, 130],
+    [253, 219, 199],
+    [247, 247, 247],
+    [209, 229, 240],
+    [146, 197, 222],
+    [67, 147, 195],
+    [33, 102, 172],
+    [5, 48, 97],
+];
+
+///The 11 class ColorBrewer Spectral scheme
+const SPECTRAL: [[u8; 3]; 11] = [
+    [158, 1, 66],
+    [213, 62, 79],
+    [244, 109, 67],
+    [253, 174, 97],
+    [254, 224, 139],
+    [255, 255, 191],
+    [230, 245, 152],
+    [171, 221, 164],
+    [102, 194, 165],
+    [50, 136, 189],
+    [94, 79, 162],
+];
+
+fn polynomial(coefficients: &[[f64; 3]], t: f64) -> [u8; 3] {
+    let mut value = [0.0; 3];
+    for coefficient in coefficients.iter().rev() {
+        for (v, c) in value.iter_mut().zip(coefficient) {
+            *v = *v * t + c;
+        }
+    }
+    value.map(|v| (v.clamp(0.0, 1.0) * 255.0).round() as u8)
+}
+
+fn stops(stops: &[[u8; 3]], t: f64) -> [u8; 3] {
+    let position = t * (stops.len() - 1) as f64;
+    let i = (position.floor() as usize).min(stops.len() - 2);
+    let fraction = position - i as f64;
+    let (a, b) = (stops[i], stops[i + 1]);
+    [0, 1, 2].map(|c| (a[c] as f64 + (b[c] as f64 - a[c] as f64) * fraction).round() as u8)
+}
+
+impl Colormap {
+    ///Every colormap
+    pub const ALL: [Self; 9] = [
+        Self::Viridis,
+        Self::Magma,
+        Self::Inferno,
+        Self::Plasma,
+        Self::Cividis,
+        Self::Turbo,
+        Self::Coolwarm,
+        Self::RdBu,
+        Self::Spectral,
+    ];
+
+    ///Get the name the colormap is parsed with, e.g. `viridis`
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Viridis => "viridis",
+            Self::Magma => "magma",
+            Self::Inferno => "inferno",
+            Self::Plasma => "plasma",
+            Self::Cividis => "cividis",
+            Self::Turbo => "turbo",
+            Self::Coolwarm => "coolwarm",
+            Self::RdBu => "rdbu",
+            Self::Spectral => "spectral",
+        }
+    }
+
+    ///Get a colormap by name, ignoring case
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|map| map.name().eq_ignore_ascii_case(name))
+    }
+
+    ///Returns whether the colormap diverges from a light center rather than running from dark to
+    ///light
+    pub fn is_diverging(&self) -> bool {
+        matches!(self, Self::Coolwarm | Self::RdBu | Self::Spectral)
+    }
+
+    fn value(&self, t: f64) -> [u8; 3] {
+        match self {
+            Self::Viridis => polynomial(&VIRIDIS, t),
+            Self::Magma => polynomial(&MAGMA, t),
+            Self::Inferno => polynomial(&INFERNO, t),
+            Self::Plasma => polynomial(&PLASMA, t),
+            Self::Cividis => stops(&CIVIDIS, t),
+            Self::Turbo => polynomial(&TURBO, t),
+            Self::Coolwarm => stops(&COOLWARM, t),
+            Self::RdBu => stops(&RDBU, t),
+            Self::Spectral => stops(&SPECTRAL, t),
+        }
+    }
+
+    ///Sample the colormap at a position from 0 to 1. Positions outside the range are clamped. The
+    ///result has the sample, e.g. `viridis(0.35)`, as its source.
+    pub fn sample(&self, t: f64) -> Rgb {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let mut rgb = Rgb::new().rgb(self.value(t));
+        rgb.set_external_source(format!("{}({})", self.name(), t));
+        rgb
+    }
+}
+
+impl Display for Colormap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+///Parse a sample such as `viridis(0.35)` or `magma(80%)`. Returns [None] if the string doesn't
+///name a colormap, and [ColorFromStrError::InvalidValue] if the position is not from 0 to 1.
+pub(crate) fn parse_sample(s: &str) -> Option<Result<[u8; 3], ColorFromStrError>> {
+    let (name, rest) = s.split_once('(')?;
+    let position = rest.strip_suffix(')')?;
+    let map = Colormap::from_name(name.trim())?;
+    Some(match number(position) {
+        Ok(t) if (0.0..=1.0).contains(&t) => Ok(map.value(t)),
+        _ => Err(ColorFromStrError::InvalidValue),
+    })
+}
+
+#[cfg(test)]
+mod colormap_tests {
+    use super::*;
+
+    #[test]
+    fn test_colormap_endpoints() {
+        //Endpoints of the matplotlib colormaps
+        let expected: [(Colormap, [u8; 3], [u8; 3]); 4] = [
+            (Colormap::Viridis, [68, 1, 84], [253, 231, 37]),
+            (Colormap::Magma, [0, 0, 4], [252, 253, 191]),
+            (Colormap::Inferno, [0, 0, 4], [252, 255, 164]),
+            (Colormap::Plasma, [13, 8, 135], [240, 249, 33]),
+        ];
+        for (map, start, end) in expected {
+            for (t, expected) in [(0.0, start), (1.0, end)] {
+                let value = map.sample(t).get_rgb();
+                let close = value.iter().zip(expected).all(|(v, e)| v.abs_diff(e) <= 8);
+                assert!(close, "{} at {}: {:?}", map, t, value);
+            }
+        }
+        let turbo = Colormap::Turbo.sample(0.5).get_rgb();
+        assert!(turbo[1] > 240 && turbo[0] < 200 && turbo[2] < 100);
+        assert_eq!(Colormap::RdBu.sample(0.5).get_rgb(), [247, 247, 247]);
+        assert_eq!(Colormap::Spectral.sample(0.0).get_rgb(), SPECTRAL[0]);
+        assert_eq!(Colormap::Spectral.sample(1.0).get_rgb(), SPECTRAL[10]);
+        assert_eq!(
+            Colormap::Coolwarm.sample(2.0),
+            Colormap::Coolwarm.sample(1.0)
+        );
+    }
+
+    #[test]
+    fn test_parse_sample() {
+        assert_eq!(
+            parse_sample("viridis(0.35)"),
+            Some(Ok(Colormap::Viridis.sample(0.35).get_rgb()))
+        );
+        assert_eq!(
+            parse_sample("Magma(80%)"),
+            Some(Ok(Colormap::Magma.sample(0.8).get_rgb()))
+        );
+        assert_eq!(
+            parse_sample("turbo(1.5)"),
+            Some(Err(ColorFromStrError::InvalidValue))
+        );
+        assert_eq!(parse_sample("css(red)"), None);
+        assert_eq!(Colormap::from_name("RdBu"), Some(Colormap::RdBu));
+    }
+}
@@ -0,0 +1,193 @@
+use super::{
+    space::{Lab, Oklab},
+    Color, Rgb,
+};
+
+///A formula for the perceptual difference between two colors.
+///
+///Every metric is scaled so that a difference of about 1-2 is just noticeable and black and white
+///are about 100 apart.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DistanceMetric {
+    ///Euclidean distance in CIELAB (ΔE*ab, 1976). Fast, but overstates differences between
+    ///saturated colors.
+    Cie76,
+    ///The CIE94 formula with graphic arts weights
+    Cie94,
+    ///The CIEDE2000 formula, the most accurate of the CIELAB metrics
+    #[default]
+    Ciede2000,
+    ///Euclidean distance in Oklab, multiplied by 100
+    Oklab,
+}
+
+fn cie76(x: Lab, y: Lab) -> f64 {
+    ((x.l - y.l).powi(2) + (x.a - y.a).powi(2) + (x.b - y.b).powi(2)).sqrt()
+}
+
+fn cie94(x: Lab, y: Lab) -> f64 {
+    let (c1, c2) = (x.a.hypot(x.b), y.a.hypot(y.b));
+    let (dl, dc) = (x.l - y.l, c1 - c2);
+    let dh2 = ((x.a - y.a).powi(2) + (x.b - y.b).powi(2) - dc * dc).max(0.0);
+    let (sc, sh) = (1.0 + 0.045 * c1, 1.0 + 0.015 * c1);
+    (dl * dl + (dc / sc).powi(2) + dh2 / (sh * sh)).sqrt()
+}
+
+fn ciede2000(x: Lab, y: Lab) -> f64 {
+    const POW25_7: f64 = 6103515625.0;
+    let c_bar = (x.a.hypot(x.b) + y.a.hypot(y.b)) / 2.0;
+    let g = 0.5 * (1.0 - (c_bar.powi(7) / (c_bar.powi(7) + POW25_7)).sqrt());
+    let (a1, a2) = ((1.0 + g) * x.a, (1.0 + g) * y.a);
+    let (c1, c2) = (a1.hypot(x.b), a2.hypot(y.b));
+    let hue = |b: f64, a: f64| {
+        if a == 0.0 && b == 0.0 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        }
+    };
+    let (h1, h2) = (hue(x.b, a1), hue(y.b, a2));
+
+    let dl = y.l - x.l;
+    let dc = c2 - c1;
+    let dh = if c1 * c2 == 0.0 {
+        0.0
+    } else if h2 - h1 > 180.0 {
+        h2 - h1 - 360.0
+    } else if h2 - h1 < -180.0 {
+        h2 - h1 + 360.0
+    } else {
+        h2 - h1
+    };
+    let dh = 2.0 * (c1 * c2).sqrt() * (dh / 2.0).to_radians().sin();
+
+    let l_bar = (x.l + y.l) / 2.0;
+    let c_bar = (c1 + c2) / 2.0;
+    let h_bar = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        (h1 + h2) / 2.0
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+    let t = 1.0 - 0.17 * (h_bar - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * h_bar).to_radians().cos()
+        + 0.32 * (3.0 * h_bar + 6.0).to_radians().cos()
+        - 0.20 * (4.0 * h_bar - 63.0).to_radians().cos();
+    let d_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+    let rc = 2.0 * (c_bar.powi(7) / (c_bar.powi(7) + POW25_7)).sqrt();
+    let sl = 1.0 + 0.015 * (l_bar - 50.0).powi(2) / (20.0 + (l_bar - 50.0).powi(2)).sqrt();
+    let sc = 1.0 + 0.045 * c_bar;
+    let sh = 1.0 + 0.015 * c_bar * t;
+    let rt = -(2.0 * d_theta).to_radians().sin() * rc;
+    ((dl / sl).powi(2) + (dc / sc).powi(2) + (dh / sh).powi(2) + rt * (dc / sc) * (dh / sh)).sqrt()
+}
+
+impl DistanceMetric {
+    ///Get the distance between two colors
+    pub fn distance(&self, a: &Rgb, b: &Rgb) -> f64 {
+        self.between(a.get_rgb(), b.get_rgb())
+    }
+
+    pub(crate) fn between(&self, a: [u8; 3], b: [u8; 3]) -> f64 {
+        match self {
+            Self::Cie76 => cie76(Lab::from_rgb(a), Lab::from_rgb(b)),
+            Self::Cie94 => cie94(Lab::from_rgb(a), Lab::from_rgb(b)),
+            Self::Ciede2000 => ciede2000(Lab::from_rgb(a), Lab::from_rgb(b)),
+            Self::Oklab => {
+                let (a, b) = (Oklab::from_rgb(a), Oklab::from_rgb(b));
+                ((a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt() * 100.0
+            }
+        }
+    }
+
+    ///Find the candidate closest to a color, returning it with its distance. The first candidate
+    ///wins ties.
+    pub(crate) fn nearest<T, I>(&self, target: [u8; 3], candidates: I) -> Option<(T, f64)>
+    where
+        I: IntoIterator<Item = (T, [u8; 3])>,
+    {
+        let mut closest: Option<(T, f64)> = None;
+        for (candidate, rgb) in candidates {
+            let distance = self.between(target, rgb);
+            match &closest {
+                Some((_, best)) if distance >= *best => {}
+                _ => closest = Some((candidate, distance)),
+            }
+        }
+        closest
+    }
+}
+
+impl Rgb {
+    ///Get the perceptual distance to another color
+    pub fn distance(&self, other: &Rgb, metric: DistanceMetric) -> f64 {
+        metric.distance(self, other)
+    }
+}
+
+impl Color {
+    ///Get the perceptual distance to another color, using the standard xterm palette for
+    ///[Ansi](super::Ansi) and [Fixed](super::Fixed). Returns [None] if either color is
+    ///[Ansi::Default](super::Ansi::Default).
+    pub fn distance(&self, other: &Color, metric: DistanceMetric) -> Option<f64> {
+        Some(metric.distance(&self.to_rgb()?, &other.to_rgb()?))
+    }
+}
+
+#[cfg(test)]
+mod distance_tests {
+    use super::*;
+    use crate::color::Ansi;
+
+    fn lab(l: f64, a: f64, b: f64) -> Lab {
+        Lab { l, a, b }
+    }
+
+    #[test]
+    fn test_ciede2000_reference_pairs() {
+        //Test data from Sharma, Wu and Dalal (2005)
+        let pairs = [
+            (
+                lab(50.0, 2.6772, -79.7751),
+                lab(50.0, 0.0, -82.7485),
+                2.0425,
+            ),
+            (lab(50.0, -1.3802, -84.2814), lab(50.0, 0.0, -82.7485), 1.0),
+            (lab(50.0, 2.5, 0.0), lab(73.0, 25.0, -18.0), 27.1492),
+            (lab(50.0, 2.5, 0.0), lab(50.0, 0.0, -2.5), 4.3065),
+            (
+                lab(2.0776, 0.0795, -1.135),
+                lab(0.9033, -0.0636, -0.5514),
+                0.9082,
+            ),
+        ];
+        for (x, y, expected) in pairs {
+            assert!((ciede2000(x, y) - expected).abs() < 1e-4);
+            assert!((ciede2000(y, x) - expected).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_metrics() {
+        let (black, white) = (Rgb::new().rgb([0; 3]), Rgb::new().rgb([255; 3]));
+        for metric in [
+            DistanceMetric::Cie76,
+            DistanceMetric::Cie94,
+            DistanceMetric::Ciede2000,
+            DistanceMetric::Oklab,
+        ] {
+            assert!((black.distance(&white, metric) - 100.0).abs() < 0.1);
+            assert_eq!(white.distance(&white, metric), 0.0);
+        }
+        let red: Color = Ansi::Red.into();
+        assert!(red.distance(&Ansi::Green.into(), DistanceMetric::default()) > Some(10.0));
+        assert_eq!(
+            red.distance(&Ansi::Default.into(), DistanceMetric::Oklab),
+            None
+        );
+    }
+}
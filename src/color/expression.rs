@@ -0,0 +1,190 @@
+use std::str::FromStr;
+
+use super::{parse_reference, Ansi, ColorFromStrError, Rgb};
+
+///Names of the functions which can be used in color expressions
+pub(crate) const FUNCTIONS: [&str; 7] = [
+    "lighten",
+    "darken",
+    "saturate",
+    "desaturate",
+    "mix",
+    "alpha",
+    "rotate_hue",
+];
+
+///Error produced while evaluating an expression, either from the expression itself or from
+///looking up a palette reference
+pub(crate) enum EvalError<E> {
+    Invalid(ColorFromStrError),
+    Lookup(E),
+}
+
+impl<E> From<ColorFromStrError> for EvalError<E> {
+    fn from(value: ColorFromStrError) -> Self {
+        Self::Invalid(value)
+    }
+}
+
+///Returns whether a string is a call to one of the expression [FUNCTIONS]
+pub(crate) fn is_expression(s: &str) -> bool {
+    s.ends_with(')')
+        && s.split_once('(')
+            .is_some_and(|(name, _)| FUNCTIONS.contains(&name.trim()))
+}
+
+///Split the arguments of a function call on the commas which are not nested in other calls
+fn split_arguments(s: &str) -> Result<(&str, Vec<&str>), ColorFromStrError> {
+    let (name, rest) = s.split_once('(').ok_or(ColorFromStrError::InvalidString)?;
+    let inner = rest
+        .strip_suffix(')')
+        .ok_or(ColorFromStrError::InvalidString)?;
+    let mut arguments = Vec::new();
+    let (mut depth, mut start) = (0i32, 0);
+    for (i, c) in inner.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                arguments.push(inner[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+        if depth < 0 {
+            return Err(ColorFromStrError::InvalidString);
+        }
+    }
+    if depth != 0 {
+        return Err(ColorFromStrError::InvalidString);
+    }
+    arguments.push(inner[start..].trim());
+    Ok((name.trim(), arguments))
+}
+
+///Parse a number argument. Percentages are converted to fractions (`30%` is `0.3`) and a `deg`
+///suffix is ignored.
+pub(crate) fn number(s: &str) -> Result<f64, ColorFromStrError> {
+    let (value, scale) = match s.strip_suffix('%') {
+        Some(value) => (value, 0.01),
+        None => (s.strip_suffix("deg").unwrap_or(s), 1.0),
+    };
+    value
+        .trim()
+        .parse::<f64>()
+        .map(|v| v * scale)
+        .map_err(|_| ColorFromStrError::InvalidValue)
+}
+
+///Parse an amount argument, which must be from 0 to 1 (or 0% to 100%)
+fn amount(s: &str) -> Result<f64, ColorFromStrError> {
+    match number(s)? {
+        amount if (0.0..=1.0).contains(&amount) => Ok(amount),
+        _ => Err(ColorFromStrError::InvalidValue),
+    }
+}
+
+fn color<E>(
+    s: &str,
+    lookup: &mut dyn FnMut(&str) -> Result<[u8; 3], E>,
+) -> Result<Rgb, EvalError<E>> {
+    if let Some(name) = parse_reference(s) {
+        return lookup(name)
+            .map(|value| Rgb::new().rgb(value))
+            .map_err(EvalError::Lookup);
+    }
+    if is_expression(s) {
+        return evaluate(s, lookup).map(|value| Rgb::new().rgb(value));
+    }
+    if let Ok(rgb) = Rgb::from_str(s) {
+        return Ok(rgb);
+    }
+    Ansi::from_str(s)
+        .ok()
+        .and_then(|ansi| ansi.to_rgb())
+        .ok_or(EvalError::Invalid(ColorFromStrError::InvalidString))
+}
+
+///Evaluate a color expression such as `mix(css(navy), #ffffff, 30%)`. Palette references are
+///resolved with `lookup`.
+pub(crate) fn evaluate<E>(
+    s: &str,
+    lookup: &mut dyn FnMut(&str) -> Result<[u8; 3], E>,
+) -> Result<[u8; 3], EvalError<E>> {
+    let (name, arguments) = split_arguments(s)?;
+    let rgb = match (name, &arguments[..]) {
+        ("lighten", [c, a]) => color(c, lookup)?.lighten(amount(a)?),
+        ("darken", [c, a]) => color(c, lookup)?.darken(amount(a)?),
+        ("saturate", [c, a]) => color(c, lookup)?.saturate(amount(a)?),
+        ("desaturate", [c, a]) => color(c, lookup)?.desaturate(amount(a)?),
+        ("rotate_hue", [c, degrees]) => color(c, lookup)?.rotate_hue(number(degrees)?),
+        ("mix", [a, b]) => color(a, lookup)?.mix(&color(b, lookup)?, 0.5),
+        ("mix", [a, b, m]) => color(a, lookup)?.mix(&color(b, lookup)?, amount(m)?),
+        ("alpha", [c, opacity, background]) => {
+            color(c, lookup)?.with_alpha_over(&color(background, lookup)?, amount(opacity)?)
+        }
+        _ => return Err(EvalError::Invalid(ColorFromStrError::InvalidString)),
+    };
+    Ok(rgb.get_rgb())
+}
+
+#[cfg(test)]
+mod expression_tests {
+    use std::convert::Infallible;
+
+    use super::*;
+    use crate::color::space::Oklch;
+
+    fn eval(s: &str) -> Result<[u8; 3], ColorFromStrError> {
+        evaluate(s, &mut |_| Ok::<_, Infallible>([0; 3])).map_err(|e| match e {
+            EvalError::Invalid(e) => e,
+            EvalError::Lookup(e) => match e {},
+        })
+    }
+
+    #[test]
+    fn test_mix() {
+        assert_eq!(eval("mix(#000000, #000000)"), Ok([0, 0, 0]));
+        assert_eq!(eval("mix(css(navy), #ffffff, 0%)"), Ok([0, 0, 128]));
+        assert_eq!(eval("mix(css(navy), #ffffff, 100%)"), Ok([255, 255, 255]));
+        let mixed = eval("mix(css(navy), #ffffff, 30%)").unwrap();
+        assert!(mixed[2] > 128 && mixed[0] > 0);
+    }
+
+    #[test]
+    fn test_lightness_and_hue() {
+        let darker = eval("darken(css(tomato), 10%)").unwrap();
+        let lighter = eval("lighten(css(tomato), 10%)").unwrap();
+        let tomato = Oklch::from_rgb([255, 99, 71]);
+        assert!(Oklch::from_rgb(darker).l < tomato.l);
+        assert!(Oklch::from_rgb(lighter).l > tomato.l);
+        let rotated = Oklch::from_rgb(eval("rotate_hue(xterm(Seafoam), 180deg)").unwrap());
+        let seafoam = Oklch::from_rgb(eval("rotate_hue(xterm(Seafoam), 0)").unwrap());
+        assert!(((rotated.h - seafoam.h).rem_euclid(360.0) - 180.0).abs() < 5.0);
+    }
+
+    #[test]
+    fn test_alpha_and_nesting() {
+        assert_eq!(eval("alpha(#ffffff, 50%, #000000)"), Ok([128, 128, 128]));
+        assert_eq!(
+            eval("alpha(mix(#ffffff, #ffffff, 50%), 0.5, Black)"),
+            Ok([128, 128, 128])
+        );
+    }
+
+    #[test]
+    fn test_invalid_expressions() {
+        assert!(eval("mix(#ffffff)").is_err());
+        assert!(eval("darken(#ffffff, lots)").is_err());
+        assert!(eval("darken(css(notacolor), 10%)").is_err());
+        assert!(eval("darken(#ffffff, 10%").is_err());
+        for expression in [
+            "lighten(#808080, 10)",
+            "darken(#808080, -10%)",
+            "mix(#000000, #ffffff, 2)",
+            "alpha(#ffffff, 1.5, #000000)",
+        ] {
+            assert_eq!(eval(expression), Err(ColorFromStrError::InvalidValue));
+        }
+    }
+}
@@ -3,11 +3,12 @@ use std::rc::Rc;
 use std::str::FromStr;
 
 use super::libraries::ColorLibrary;
-use super::libraries::XtermColors;
-use super::{ColorFromStrError, ColorSource, ColorValue, Source};
+use super::libraries::{Xterm256Colors, XtermColors};
+use super::registry::{ColorRegistry, CustomColor};
+use super::{parse_reference, ColorFromStrError, ColorSource, ColorValue, Rgb, Source};
 
-///Ansi256 color value represented by a u8. Can be created from [XtermColors] name with
-///[FromStr].
+///Ansi256 color value represented by a u8. Can be created from an [XtermColors] or
+///[Xterm256Colors] name, or a [CustomColor::Fixed] name from the [ColorRegistry], with [FromStr].
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct Fixed(pub u8, Source<Rc<str>>);
 
@@ -21,7 +22,15 @@ impl Fixed {
     pub fn from(color: XtermColors) -> Self {
         Self(
             color.ansi256(),
-            Source::Active(Rc::from(color.color_name())),
+            Source::Active(Rc::from(XtermColors::wrap_name(color.color_name()))),
+        )
+    }
+
+    ///Create a new Ansi256 from Xterm256Colors
+    pub fn from_xterm256(color: Xterm256Colors) -> Self {
+        Self(
+            color.ansi256(),
+            Source::Active(Rc::from(Xterm256Colors::wrap_name(color.color_name()))),
         )
     }
 
@@ -33,7 +42,7 @@ impl Fixed {
     /// Set the [XtermColors] of the color
     pub fn set_color(&mut self, color: XtermColors) {
         self.0 = color.ansi256();
-        self.1 = Source::Active(Rc::from(color.color_name()));
+        self.1 = Source::Active(Rc::from(XtermColors::wrap_name(color.color_name())));
     }
 
     ///Set the [XtermColors] of the color
@@ -52,6 +61,24 @@ impl Fixed {
     pub fn get_color(&self) -> XtermColors {
         XtermColors::get_ansi256(self.0)
     }
+
+    ///Get the name of the palette entry this color references, if its active source is a palette
+    ///reference (`$name` or `palette(name)`)
+    pub fn palette_reference(&self) -> Option<&str> {
+        self.active_source().and_then(parse_reference)
+    }
+
+    pub(crate) fn active_source(&self) -> Option<&str> {
+        match &self.1 {
+            Source::Active(s) => Some(s),
+            Source::Inactive(_) => None,
+        }
+    }
+
+    ///Get the [Rgb] value of the color in the standard xterm palette
+    pub fn to_rgb(&self) -> Rgb {
+        Rgb::new().rgb(self.get_color().rgb())
+    }
 }
 
 impl Display for Fixed {
@@ -77,29 +104,39 @@ impl From<XtermColors> for Fixed {
     fn from(value: XtermColors) -> Self {
         Self(
             value.ansi256(),
-            Source::Active(Rc::from(value.color_name())),
+            Source::Active(Rc::from(XtermColors::wrap_name(value.color_name()))),
         )
     }
 }
 
+impl From<Xterm256Colors> for Fixed {
+    fn from(value: Xterm256Colors) -> Self {
+        Self::from_xterm256(value)
+    }
+}
+
 impl ColorValue for Fixed {}
 
 impl FromStr for Fixed {
     type Err = ColorFromStrError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.starts_with("xterm(") && s.ends_with(")") {
-            let color_name = XtermColors::unwrap_name(s);
-            let xterm_color = XtermColors::get_name(color_name);
-            if let Some(color) = xterm_color {
-                return Ok(Self(
-                    color.ansi256(),
-                    Source::Active(Rc::from(color.color_name())),
-                ));
-            } else {
-                return Err(ColorFromStrError::InvalidName);
+        let code = if s.starts_with("xterm(") && s.ends_with(")") {
+            XtermColors::get_name(XtermColors::unwrap_name(s)).map(|color| color.ansi256())
+        } else if s.starts_with("xterm256(") && s.ends_with(")") {
+            Xterm256Colors::get_name(Xterm256Colors::unwrap_name(s)).map(|color| color.ansi256())
+        } else if let Some(color) = ColorRegistry::lookup(s) {
+            match color {
+                CustomColor::Fixed(code) => Some(code),
+                CustomColor::Rgb(_) => None,
             }
+        } else {
+            return Err(ColorFromStrError::InvalidString);
+        };
+        match code {
+            //Keep the name as it was written, which may differ from the library name
+            Some(code) => Ok(Self(code, Source::Active(Rc::from(s)))),
+            None => Err(ColorFromStrError::InvalidName),
         }
-        Err(ColorFromStrError::InvalidString)
     }
 }
 
@@ -110,7 +147,10 @@ impl serde::Serialize for Fixed {
         S: serde::Serializer,
     {
         if let Source::Active(s) = self.1.clone() {
-            return serializer.serialize_str(&XtermColors::wrap_name(&s));
+            if XtermColors::get_name(&s).is_some() {
+                return serializer.serialize_str(&XtermColors::wrap_name(&s));
+            }
+            return serializer.serialize_str(&s);
         }
         serializer.serialize_u8(self.0)
     }
@@ -137,16 +177,7 @@ impl<'de> serde::de::Visitor<'de> for FixedVisitor {
     where
         E: serde::de::Error,
     {
-        if v.trim().starts_with("xterm(") && v.trim().ends_with(")") {
-            let name = &v[6..v.len() - 1];
-            if let Some(color) = XtermColors::get_name(name) {
-                return Ok(Fixed(
-                    color.ansi256(),
-                    Source::Active(Rc::from(color.color_name())),
-                ));
-            }
-        }
-        Err(E::custom("Invalid xterm theme"))
+        Fixed::from_str(v.trim()).map_err(|_| E::custom("Invalid xterm theme"))
     }
 }
 
@@ -165,6 +196,7 @@ mod fixed_tests {
     use serde_test::{assert_tokens, Token};
 
     use super::*;
+    use crate::color::registry::CustomLibrary;
     #[test]
     fn test_fixed_serialize_u8() {
         let mut fixed = Fixed::new();
@@ -176,5 +208,40 @@ mod fixed_tests {
     fn test_fixed_serialize_source() {
         let fixed = Fixed::new().color(XtermColors::Seafoam);
         assert_tokens(&fixed, &[Token::Str("xterm(Seafoam)")]);
+        let fixed = Fixed::from_str("xterm(sea_foam)").unwrap();
+        assert_eq!(fixed.0, XtermColors::Seafoam.ansi256());
+        assert_tokens(&fixed, &[Token::Str("xterm(sea_foam)")]);
+    }
+
+    #[test]
+    fn test_fixed_registry() {
+        let library = CustomLibrary::new("fixedbrand")
+            .fixed("accent", 208)
+            .rgb("primary", [255, 95, 0]);
+        ColorRegistry::register(library).unwrap();
+        let fixed = Fixed::from_str("fixedbrand(Accent)").unwrap();
+        assert_eq!(fixed.0, 208);
+        assert_tokens(&fixed, &[Token::Str("fixedbrand(Accent)")]);
+        assert_eq!(
+            Fixed::from_str("fixedbrand(primary)"),
+            Err(ColorFromStrError::InvalidName)
+        );
+    }
+
+    #[test]
+    fn test_fixed_xterm256_names() {
+        let fixed = Fixed::from_str("xterm256(DarkOliveGreen3)").unwrap();
+        assert_eq!(fixed.0, 107);
+        assert_tokens(&fixed, &[Token::Str("xterm256(DarkOliveGreen3)")]);
+        assert_eq!(Fixed::from_str("xterm256(grey93)").unwrap().0, 255);
+        assert_eq!(Fixed::from_xterm256(Xterm256Colors::Blue3_20).0, 20);
+        assert_tokens(
+            &Fixed::from_xterm256(Xterm256Colors::SkyBlue1),
+            &[Token::Str("xterm256(SkyBlue1)")],
+        );
+        assert_eq!(
+            Fixed::from_str("xterm256(Seafoam)"),
+            Err(ColorFromStrError::InvalidName)
+        );
     }
 }
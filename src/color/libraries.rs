@@ -1,17 +1,114 @@
 /*!
 
-[CssColors](libraries::CssColors) and [XtermColors](libraries::XtermColors) are re-exported from private modules which contain macros to generate color enums.
-These enums implement [ColorLibrary](libraries::ColorLibrary).
+[CssColors](libraries::CssColors), [XtermColors](libraries::XtermColors) and [Xterm256Colors](libraries::Xterm256Colors) are re-exported from private modules which contain macros to generate color enums.
+These enums implement [ColorLibrary](libraries::ColorLibrary) and
+[EnumerableLibrary](libraries::EnumerableLibrary).
+
+Libraries for popular palettes are each behind a cargo feature of the same name, or all of them
+behind `palettes`:
+- `tailwind`: [TailwindColors](libraries::TailwindColors), e.g. `tailwind(blue-500)`
+- `material`: [MaterialColors](libraries::MaterialColors), e.g. `material(deep-purple-a200)`
+- `catppuccin`: [CatppuccinColors](libraries::CatppuccinColors), e.g. `catppuccin(mocha.mauve)`
+- `nord`: [NordColors](libraries::NordColors), e.g. `nord(nord8)`
+- `solarized`: [SolarizedColors](libraries::SolarizedColors), e.g. `solarized(base03)`
+- `gruvbox`: [GruvboxColors](libraries::GruvboxColors), e.g. `gruvbox(bright_orange)`
+- `dracula`: [DraculaColors](libraries::DraculaColors), e.g. `dracula(purple)`
 
 */
 
+#[cfg(feature = "catppuccin")]
+mod catppuccin;
 mod css;
+#[cfg(feature = "dracula")]
+mod dracula;
+#[cfg(feature = "gruvbox")]
+mod gruvbox;
+#[cfg(feature = "material")]
+mod material;
+#[cfg(feature = "nord")]
+mod nord;
+#[cfg(feature = "solarized")]
+mod solarized;
+#[cfg(feature = "tailwind")]
+mod tailwind;
 mod xterm;
+mod xterm256;
+
+use std::collections::HashMap;
+
+use super::{DistanceMetric, Rgb};
 
 #[doc(inline)]
 pub use css::CssColors;
 #[doc(inline)]
 pub use xterm::XtermColors;
+#[doc(inline)]
+pub use xterm256::Xterm256Colors;
+
+#[cfg(feature = "catppuccin")]
+#[doc(inline)]
+pub use catppuccin::CatppuccinColors;
+#[cfg(feature = "dracula")]
+#[doc(inline)]
+pub use dracula::DraculaColors;
+#[cfg(feature = "gruvbox")]
+#[doc(inline)]
+pub use gruvbox::GruvboxColors;
+#[cfg(feature = "material")]
+#[doc(inline)]
+pub use material::MaterialColors;
+#[cfg(feature = "nord")]
+#[doc(inline)]
+pub use nord::NordColors;
+#[cfg(feature = "solarized")]
+#[doc(inline)]
+pub use solarized::SolarizedColors;
+#[cfg(feature = "tailwind")]
+#[doc(inline)]
+pub use tailwind::TailwindColors;
+
+///Normalize a color name for lookups, ignoring case, spaces, underscores and hyphens, and
+///spelling `gray` as `grey`
+pub(crate) fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, ' ' | '_' | '-'))
+        .flat_map(char::to_lowercase)
+        .collect::<String>()
+        .replace("gray", "grey")
+}
+
+///Get the RGB value of a name wrapped in the wrapper of a library, e.g. `css(red)`
+fn get_wrapped<C: ColorLibrary>(s: &str) -> Option<[u8; 3]> {
+    let wrapper = C::WRAPPER.strip_suffix("()")?;
+    let name = s
+        .strip_prefix(wrapper)?
+        .strip_prefix('(')?
+        .strip_suffix(')')?;
+    C::get_name(name).map(|color| color.rgb())
+}
+
+///Get the RGB value of a wrapped name from any built in library, including the palette libraries
+///enabled by features
+pub(crate) fn wrapped_rgb(s: &str) -> Option<[u8; 3]> {
+    let found = get_wrapped::<CssColors>(s)
+        .or_else(|| get_wrapped::<XtermColors>(s))
+        .or_else(|| get_wrapped::<Xterm256Colors>(s));
+    #[cfg(feature = "tailwind")]
+    let found = found.or_else(|| get_wrapped::<TailwindColors>(s));
+    #[cfg(feature = "material")]
+    let found = found.or_else(|| get_wrapped::<MaterialColors>(s));
+    #[cfg(feature = "catppuccin")]
+    let found = found.or_else(|| get_wrapped::<CatppuccinColors>(s));
+    #[cfg(feature = "nord")]
+    let found = found.or_else(|| get_wrapped::<NordColors>(s));
+    #[cfg(feature = "solarized")]
+    let found = found.or_else(|| get_wrapped::<SolarizedColors>(s));
+    #[cfg(feature = "gruvbox")]
+    let found = found.or_else(|| get_wrapped::<GruvboxColors>(s));
+    #[cfg(feature = "dracula")]
+    let found = found.or_else(|| get_wrapped::<DraculaColors>(s));
+    found
+}
 
 ///Trait defining common functions for macro based colour libraries ([xterm], [css])
 pub trait ColorLibrary
@@ -26,7 +123,11 @@ where
     ///Extract a string from the style wrapper
     fn unwrap_name(s: &str) -> &str;
 
-    ///Get a color by name
+    ///Alternative names for colors, as `(alias, name)` pairs
+    const ALIASES: &'static [(&'static str, &'static str)] = &[];
+
+    ///Get a color by name. The built in libraries ignore case, spaces, underscores and hyphens,
+    ///treat `gray` and `grey` as interchangeable, and accept [aliases](ColorLibrary::ALIASES).
     fn get_name(s: &str) -> Option<Self>;
 
     ///Get the name of a color
@@ -34,4 +135,211 @@ where
 
     ///Get the RGB value of a colour
     fn rgb(&self) -> [u8; 3];
+
+    ///Get the closest color to an RGB value with a [DistanceMetric], along with its distance. The
+    ///first color in the library wins ties. Returns [None] if the library has no colors.
+    fn nearest_with(rgb: &Rgb, metric: DistanceMetric) -> Option<(Self, f64)>;
+
+    ///Get the closest color to an RGB value with the default [DistanceMetric], along with its
+    ///distance. Returns [None] if the library has no colors.
+    fn nearest(rgb: &Rgb) -> Option<(Self, f64)> {
+        Self::nearest_with(rgb, DistanceMetric::default())
+    }
+
+    ///Get every color with exactly this RGB value
+    fn exact_matches(rgb: &Rgb) -> Vec<Self>;
+}
+
+///A [ColorLibrary] which can list all of its colors. Implemented by every built in library.
+pub trait EnumerableLibrary: ColorLibrary + Copy + 'static {
+    ///Every color in the library, in definition order
+    const ALL: &'static [Self];
+
+    ///Get a color by its exact name
+    fn get_exact_name(s: &str) -> Option<Self>;
+
+    ///Iterate over every color in the library, in definition order
+    fn iter() -> std::iter::Copied<std::slice::Iter<'static, Self>> {
+        Self::ALL.iter().copied()
+    }
+
+    ///Get the number of colors in the library
+    fn len() -> usize {
+        Self::ALL.len()
+    }
+
+    ///Get the first color in the library with exactly this RGB value
+    fn from_rgb(rgb: [u8; 3]) -> Option<Self> {
+        Self::iter().find(|color| color.rgb() == rgb)
+    }
+}
+
+///Build the table of normalized names and aliases used by [ColorLibrary::get_name]. Libraries
+///build it once, so a lookup only normalizes the name it is given. When names collide, the first
+///color wins and names take precedence over aliases.
+pub(crate) fn normalized_names<C: EnumerableLibrary>() -> HashMap<String, C> {
+    let mut names = HashMap::new();
+    for color in C::iter() {
+        names
+            .entry(normalize_name(color.color_name()))
+            .or_insert(color);
+    }
+    for (alias, target) in C::ALIASES {
+        if let Some(color) = C::get_exact_name(target) {
+            names.entry(normalize_name(alias)).or_insert(color);
+        }
+    }
+    names
+}
+
+///Get the closest color in a library, for [ColorLibrary::nearest_with]
+pub(crate) fn find_nearest<C: EnumerableLibrary>(
+    rgb: &Rgb,
+    metric: DistanceMetric,
+) -> Option<(C, f64)> {
+    let candidates = C::iter().map(|color| (color, color.rgb()));
+    metric.nearest(rgb.get_rgb(), candidates)
+}
+
+///Get the colors in a library with exactly this RGB value, for [ColorLibrary::exact_matches]
+pub(crate) fn find_exact_matches<C: EnumerableLibrary>(rgb: &Rgb) -> Vec<C> {
+    C::iter()
+        .filter(|color| color.rgb() == rgb.get_rgb())
+        .collect()
+}
+
+#[cfg(test)]
+mod libraries_tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_iterate_libraries() {
+        assert_eq!(XtermColors::len(), 256);
+        assert!(XtermColors::iter()
+            .enumerate()
+            .all(|(i, color)| color.ansi256() as usize == i));
+        assert_eq!(CssColors::len(), CssColors::ALL.len());
+        let names: HashSet<_> = CssColors::iter().map(|color| color.color_name()).collect();
+        assert_eq!(names.len(), CssColors::len());
+        assert!(
+            CssColors::iter().all(|color| CssColors::get_name(color.color_name()) == Some(color))
+        );
+    }
+
+    #[test]
+    fn test_xterm256_library() {
+        assert_eq!(Xterm256Colors::len(), 256);
+        assert!(Xterm256Colors::iter().enumerate().all(|(i, color)| {
+            color.ansi256() as usize == i && color.rgb() == XtermColors::get_ansi256(i as u8).rgb()
+        }));
+        assert_eq!(
+            Xterm256Colors::get_name("DarkOliveGreen3").map(|c| c.ansi256()),
+            Some(107)
+        );
+        assert_eq!(
+            Xterm256Colors::get_name("grey 93"),
+            Some(Xterm256Colors::Grey93)
+        );
+    }
+
+    #[test]
+    fn test_nearest_in_empty_library() {
+        #[derive(Clone, Copy)]
+        struct Empty;
+        impl ColorLibrary for Empty {
+            const WRAPPER: &str = "empty";
+            fn wrap_name(s: &str) -> String {
+                format!("empty({})", s)
+            }
+            fn unwrap_name(s: &str) -> &str {
+                s
+            }
+            fn get_name(_: &str) -> Option<Self> {
+                None
+            }
+            fn color_name(&self) -> &'static str {
+                ""
+            }
+            fn rgb(&self) -> [u8; 3] {
+                [0; 3]
+            }
+            fn nearest_with(rgb: &Rgb, metric: DistanceMetric) -> Option<(Self, f64)> {
+                find_nearest(rgb, metric)
+            }
+            fn exact_matches(rgb: &Rgb) -> Vec<Self> {
+                find_exact_matches(rgb)
+            }
+        }
+        impl EnumerableLibrary for Empty {
+            const ALL: &'static [Self] = &[];
+            fn get_exact_name(_: &str) -> Option<Self> {
+                None
+            }
+        }
+        assert!(Empty::nearest(&Rgb::new()).is_none());
+        assert!(Empty::exact_matches(&Rgb::new()).is_empty());
+    }
+
+    #[cfg(feature = "palettes")]
+    #[test]
+    fn test_palette_libraries() {
+        fn unique_names<C: EnumerableLibrary>() -> bool {
+            let names: HashSet<_> = C::iter().map(|color| color.color_name()).collect();
+            names.len() == C::len()
+        }
+        assert!(unique_names::<TailwindColors>());
+        assert!(unique_names::<MaterialColors>());
+        assert!(unique_names::<CatppuccinColors>());
+        assert!(unique_names::<NordColors>());
+        assert!(unique_names::<SolarizedColors>());
+        assert!(unique_names::<GruvboxColors>());
+        assert!(unique_names::<DraculaColors>());
+        assert_eq!(TailwindColors::len(), 244);
+        assert_eq!(CatppuccinColors::len(), 104);
+
+        assert_eq!(wrapped_rgb("tailwind(blue-500)"), Some([59, 130, 246]));
+        assert_eq!(wrapped_rgb("tailwind(Blue 500)"), Some([59, 130, 246]));
+        assert_eq!(
+            wrapped_rgb("material(deep-purple-a200)"),
+            Some([124, 77, 255])
+        );
+        assert_eq!(wrapped_rgb("material(blue-gray-500)"), Some([96, 125, 139]));
+        assert_eq!(
+            wrapped_rgb("catppuccin(mocha.mauve)"),
+            Some([203, 166, 247])
+        );
+        assert_eq!(wrapped_rgb("nord(nord8)"), Some([136, 192, 208]));
+        assert_eq!(wrapped_rgb("nord(red)"), wrapped_rgb("nord(nord11)"));
+        assert_eq!(wrapped_rgb("solarized(base03)"), Some([0, 43, 54]));
+        assert_eq!(wrapped_rgb("gruvbox(bright_orange)"), Some([254, 128, 25]));
+        assert_eq!(wrapped_rgb("dracula(selection)"), Some([68, 71, 90]));
+        assert_eq!(wrapped_rgb("nord(mocha.mauve)"), None);
+    }
+
+    #[test]
+    fn test_normalized_names() {
+        assert_eq!(CssColors::get_name("Red"), Some(CssColors::Red));
+        assert_eq!(CssColors::get_name("dark_grey"), Some(CssColors::DarkGray));
+        assert_eq!(
+            CssColors::get_name("Rebecca Purple"),
+            Some(CssColors::RebeccaPurple)
+        );
+        assert_eq!(XtermColors::get_name("seafoam"), Some(XtermColors::Seafoam));
+        assert_eq!(XtermColors::get_name("gray-93"), Some(XtermColors::Grey93));
+        assert_eq!(
+            XtermColors::get_name("light lavender"),
+            Some(XtermColors::LightLavendar)
+        );
+        assert_eq!(CssColors::get_exact_name("Red"), None);
+        assert_eq!(CssColors::get_name("notacolor"), None);
+    }
+
+    #[test]
+    fn test_from_rgb() {
+        assert_eq!(CssColors::from_rgb([255, 99, 71]), Some(CssColors::Tomato));
+        assert_eq!(XtermColors::from_rgb([0, 0, 0]), Some(XtermColors::Black));
+        assert_eq!(XtermColors::from_rgb([1, 2, 3]), None);
+    }
 }
@@ -0,0 +1,112 @@
+use super::css::css_colors;
+use super::{ColorLibrary, EnumerableLibrary};
+
+css_colors! {
+    ///The four Catppuccin flavors, e.g. `catppuccin(mocha.mauve)`
+    CatppuccinColors("catppuccin") {
+        LatteRosewater, "latte.rosewater", (220, 138, 120);
+        LatteFlamingo, "latte.flamingo", (221, 120, 120);
+        LattePink, "latte.pink", (234, 118, 203);
+        LatteMauve, "latte.mauve", (136, 57, 239);
+        LatteRed, "latte.red", (210, 15, 57);
+        LatteMaroon, "latte.maroon", (230, 69, 83);
+        LattePeach, "latte.peach", (254, 100, 11);
+        LatteYellow, "latte.yellow", (223, 142, 29);
+        LatteGreen, "latte.green", (64, 160, 43);
+        LatteTeal, "latte.teal", (23, 146, 153);
+        LatteSky, "latte.sky", (4, 165, 229);
+        LatteSapphire, "latte.sapphire", (32, 159, 181);
+        LatteBlue, "latte.blue", (30, 102, 245);
+        LatteLavender, "latte.lavender", (114, 135, 253);
+        LatteText, "latte.text", (76, 79, 105);
+        LatteSubtext1, "latte.subtext1", (92, 95, 119);
+        LatteSubtext0, "latte.subtext0", (108, 111, 133);
+        LatteOverlay2, "latte.overlay2", (124, 127, 147);
+        LatteOverlay1, "latte.overlay1", (140, 143, 161);
+        LatteOverlay0, "latte.overlay0", (156, 160, 176);
+        LatteSurface2, "latte.surface2", (172, 176, 190);
+        LatteSurface1, "latte.surface1", (188, 192, 204);
+        LatteSurface0, "latte.surface0", (204, 208, 218);
+        LatteBase, "latte.base", (239, 241, 245);
+        LatteMantle, "latte.mantle", (230, 233, 239);
+        LatteCrust, "latte.crust", (220, 224, 232);
+        FrappeRosewater, "frappe.rosewater", (242, 213, 207);
+        FrappeFlamingo, "frappe.flamingo", (238, 190, 190);
+        FrappePink, "frappe.pink", (244, 184, 228);
+        FrappeMauve, "frappe.mauve", (202, 158, 230);
+        FrappeRed, "frappe.red", (231, 130, 132);
+        FrappeMaroon, "frappe.maroon", (234, 153, 156);
+        FrappePeach, "frappe.peach", (239, 159, 118);
+        FrappeYellow, "frappe.yellow", (229, 200, 144);
+        FrappeGreen, "frappe.green", (166, 209, 137);
+        FrappeTeal, "frappe.teal", (129, 200, 190);
+        FrappeSky, "frappe.sky", (153, 209, 219);
+        FrappeSapphire, "frappe.sapphire", (133, 193, 220);
+        FrappeBlue, "frappe.blue", (140, 170, 238);
+        FrappeLavender, "frappe.lavender", (186, 187, 241);
+        FrappeText, "frappe.text", (198, 208, 245);
+        FrappeSubtext1, "frappe.subtext1", (181, 191, 226);
+        FrappeSubtext0, "frappe.subtext0", (165, 173, 206);
+        FrappeOverlay2, "frappe.overlay2", (148, 156, 187);
+        FrappeOverlay1, "frappe.overlay1", (131, 139, 167);
+        FrappeOverlay0, "frappe.overlay0", (115, 121, 148);
+        FrappeSurface2, "frappe.surface2", (98, 104, 128);
+        FrappeSurface1, "frappe.surface1", (81, 87, 109);
+        FrappeSurface0, "frappe.surface0", (65, 69, 89);
+        FrappeBase, "frappe.base", (48, 52, 70);
+        FrappeMantle, "frappe.mantle", (41, 44, 60);
+        FrappeCrust, "frappe.crust", (35, 38, 52);
+        MacchiatoRosewater, "macchiato.rosewater", (244, 219, 214);
+        MacchiatoFlamingo, "macchiato.flamingo", (240, 198, 198);
+        MacchiatoPink, "macchiato.pink", (245, 189, 230);
+        MacchiatoMauve, "macchiato.mauve", (198, 160, 246);
+        MacchiatoRed, "macchiato.red", (237, 135, 150);
+        MacchiatoMaroon, "macchiato.maroon", (238, 153, 160);
+        MacchiatoPeach, "macchiato.peach", (245, 169, 127);
+        MacchiatoYellow, "macchiato.yellow", (238, 212, 159);
+        MacchiatoGreen, "macchiato.green", (166, 218, 149);
+        MacchiatoTeal, "macchiato.teal", (139, 213, 202);
+        MacchiatoSky, "macchiato.sky", (145, 215, 227);
+        MacchiatoSapphire, "macchiato.sapphire", (125, 196, 228);
+        MacchiatoBlue, "macchiato.blue", (138, 173, 244);
+        MacchiatoLavender, "macchiato.lavender", (183, 189, 248);
+        MacchiatoText, "macchiato.text", (202, 211, 245);
+        MacchiatoSubtext1, "macchiato.subtext1", (184, 192, 224);
+        MacchiatoSubtext0, "macchiato.subtext0", (165, 173, 203);
+        MacchiatoOverlay2, "macchiato.overlay2", (147, 154, 183);
+        MacchiatoOverlay1, "macchiato.overlay1", (128, 135, 162);
+        MacchiatoOverlay0, "macchiato.overlay0", (110, 115, 141);
+        MacchiatoSurface2, "macchiato.surface2", (91, 96, 120);
+        MacchiatoSurface1, "macchiato.surface1", (73, 77, 100);
+        MacchiatoSurface0, "macchiato.surface0", (54, 58, 79);
+        MacchiatoBase, "macchiato.base", (36, 39, 58);
+        MacchiatoMantle, "macchiato.mantle", (30, 32, 48);
+        MacchiatoCrust, "macchiato.crust", (24, 25, 38);
+        MochaRosewater, "mocha.rosewater", (245, 224, 220);
+        MochaFlamingo, "mocha.flamingo", (242, 205, 205);
+        MochaPink, "mocha.pink", (245, 194, 231);
+        MochaMauve, "mocha.mauve", (203, 166, 247);
+        MochaRed, "mocha.red", (243, 139, 168);
+        MochaMaroon, "mocha.maroon", (235, 160, 172);
+        MochaPeach, "mocha.peach", (250, 179, 135);
+        MochaYellow, "mocha.yellow", (249, 226, 175);
+        MochaGreen, "mocha.green", (166, 227, 161);
+        MochaTeal, "mocha.teal", (148, 226, 213);
+        MochaSky, "mocha.sky", (137, 220, 235);
+        MochaSapphire, "mocha.sapphire", (116, 199, 236);
+        MochaBlue, "mocha.blue", (137, 180, 250);
+        MochaLavender, "mocha.lavender", (180, 190, 254);
+        MochaText, "mocha.text", (205, 214, 244);
+        MochaSubtext1, "mocha.subtext1", (186, 194, 222);
+        MochaSubtext0, "mocha.subtext0", (166, 173, 200);
+        MochaOverlay2, "mocha.overlay2", (147, 153, 178);
+        MochaOverlay1, "mocha.overlay1", (127, 132, 156);
+        MochaOverlay0, "mocha.overlay0", (108, 112, 134);
+        MochaSurface2, "mocha.surface2", (88, 91, 112);
+        MochaSurface1, "mocha.surface1", (69, 71, 90);
+        MochaSurface0, "mocha.surface0", (49, 50, 68);
+        MochaBase, "mocha.base", (30, 30, 46);
+        MochaMantle, "mocha.mantle", (24, 24, 37);
+        MochaCrust, "mocha.crust", (17, 17, 27);
+    }
+}
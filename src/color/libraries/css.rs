@@ -1,31 +1,40 @@
-use super::ColorLibrary;
+use super::{ColorLibrary, EnumerableLibrary};
 
 macro_rules! css_colors {
     (
-        $enum_name:ident {
+        $(#[$meta:meta])*
+        $enum_name:ident($wrapper:literal) {
             $($variant_name:ident, $css_name:expr, ($r:expr, $g:expr, $b:expr));* $(;)?
         }
+        $(aliases {
+            $($alias:expr => $target:expr);* $(;)?
+        })?
     ) => {
         // Define the enum
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
         pub enum $enum_name {
             $(
+                #[doc = $css_name]
                 $variant_name,
             )*
         }
 
         impl ColorLibrary for $enum_name {
-            const WRAPPER: &str = "css()";
+            const WRAPPER: &str = concat!($wrapper, "()");
+
+            const ALIASES: &'static [(&'static str, &'static str)] = &[$($(($alias, $target),)*)?];
 
             fn wrap_name(str: &str) -> String {
-                let wrapped_str = format!("css({})", str);
+                let wrapped_str = format!(concat!($wrapper, "({})"), str);
                 return wrapped_str;
             }
 
             fn unwrap_name(str: &str) -> &str {
-                if str.len() < "css()".len() {
+                if str.len() < Self::WRAPPER.len() {
                     return str;
                 }
-                return &str[4..str.len() - 1];
+                return &str[$wrapper.len() + 1..str.len() - 1];
             }
 
             fn color_name(&self) -> &'static str {
@@ -36,20 +45,43 @@ macro_rules! css_colors {
                 }
             }
 
-            fn get_name(name: &str) -> Option<Self> {
-                match name {
+            fn rgb(&self) -> [u8;3] {
+                match self {
                     $(
-                        $css_name => Some(Self::$variant_name),
+                        $enum_name::$variant_name=> [$r, $g, $b],
                     )*
-                    _ => None
                 }
             }
 
-            fn rgb(&self) -> [u8;3] {
-                match self {
+            fn get_name(name: &str) -> Option<Self> {
+                static NAMES: std::sync::OnceLock<std::collections::HashMap<String, $enum_name>> =
+                    std::sync::OnceLock::new();
+                Self::get_exact_name(name).or_else(|| {
+                    NAMES
+                        .get_or_init(super::normalized_names)
+                        .get(&super::normalize_name(name))
+                        .copied()
+                })
+            }
+
+            fn nearest_with(rgb: &super::Rgb, metric: super::DistanceMetric) -> Option<(Self, f64)> {
+                super::find_nearest(rgb, metric)
+            }
+
+            fn exact_matches(rgb: &super::Rgb) -> Vec<Self> {
+                super::find_exact_matches(rgb)
+            }
+        }
+
+        impl EnumerableLibrary for $enum_name {
+            const ALL: &'static [Self] = &[$(Self::$variant_name,)*];
+
+            fn get_exact_name(name: &str) -> Option<Self> {
+                match name {
                     $(
-                        $enum_name::$variant_name=> [$r, $g, $b],
+                        $css_name => Some(Self::$variant_name),
                     )*
+                    _ => None
                 }
             }
         }
@@ -57,8 +89,13 @@ macro_rules! css_colors {
     };
 }
 
+//Only used by the palette libraries, which are behind features
+#[allow(unused_imports)]
+pub(super) use css_colors;
+
 css_colors! {
-    CssColors {
+    ///List of CSS colors by name
+    CssColors("css") {
         AliceBlue, "aliceblue", (240, 248, 255);
         AntiqueWhite, "antiquewhite", (250, 235, 215);
         Aqua, "aqua", (0, 255, 255);
@@ -0,0 +1,22 @@
+use super::css::css_colors;
+use super::{ColorLibrary, EnumerableLibrary};
+
+css_colors! {
+    ///The Dracula color palette, e.g. `dracula(purple)`
+    DraculaColors("dracula") {
+        Background, "background", (40, 42, 54);
+        CurrentLine, "current-line", (68, 71, 90);
+        Foreground, "foreground", (248, 248, 242);
+        Comment, "comment", (98, 114, 164);
+        Cyan, "cyan", (139, 233, 253);
+        Green, "green", (80, 250, 123);
+        Orange, "orange", (255, 184, 108);
+        Pink, "pink", (255, 121, 198);
+        Purple, "purple", (189, 147, 249);
+        Red, "red", (255, 85, 85);
+        Yellow, "yellow", (241, 250, 140);
+    }
+    aliases {
+        "selection" => "current-line";
+    }
+}
@@ -0,0 +1,44 @@
+use super::css::css_colors;
+use super::{ColorLibrary, EnumerableLibrary};
+
+css_colors! {
+    ///The Gruvbox color palette, e.g. `gruvbox(bright_orange)`
+    GruvboxColors("gruvbox") {
+        Dark0Hard, "dark0_hard", (29, 32, 33);
+        Dark0, "dark0", (40, 40, 40);
+        Dark0Soft, "dark0_soft", (50, 48, 47);
+        Dark1, "dark1", (60, 56, 54);
+        Dark2, "dark2", (80, 73, 69);
+        Dark3, "dark3", (102, 92, 84);
+        Dark4, "dark4", (124, 111, 100);
+        Light0Hard, "light0_hard", (249, 245, 215);
+        Light0, "light0", (251, 241, 199);
+        Light0Soft, "light0_soft", (242, 229, 188);
+        Light1, "light1", (235, 219, 178);
+        Light2, "light2", (213, 196, 161);
+        Light3, "light3", (189, 174, 147);
+        Light4, "light4", (168, 153, 132);
+        Gray, "gray", (146, 131, 116);
+        BrightRed, "bright_red", (251, 73, 52);
+        BrightGreen, "bright_green", (184, 187, 38);
+        BrightYellow, "bright_yellow", (250, 189, 47);
+        BrightBlue, "bright_blue", (131, 165, 152);
+        BrightPurple, "bright_purple", (211, 134, 155);
+        BrightAqua, "bright_aqua", (142, 192, 124);
+        BrightOrange, "bright_orange", (254, 128, 25);
+        NeutralRed, "neutral_red", (204, 36, 29);
+        NeutralGreen, "neutral_green", (152, 151, 26);
+        NeutralYellow, "neutral_yellow", (215, 153, 33);
+        NeutralBlue, "neutral_blue", (69, 133, 136);
+        NeutralPurple, "neutral_purple", (177, 98, 134);
+        NeutralAqua, "neutral_aqua", (104, 157, 106);
+        NeutralOrange, "neutral_orange", (214, 93, 14);
+        FadedRed, "faded_red", (157, 0, 6);
+        FadedGreen, "faded_green", (121, 116, 14);
+        FadedYellow, "faded_yellow", (181, 118, 20);
+        FadedBlue, "faded_blue", (7, 102, 120);
+        FadedPurple, "faded_purple", (143, 63, 113);
+        FadedAqua, "faded_aqua", (66, 123, 88);
+        FadedOrange, "faded_orange", (175, 58, 3);
+    }
+}
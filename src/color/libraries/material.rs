@@ -0,0 +1,264 @@
+use super::css::css_colors;
+use super::{ColorLibrary, EnumerableLibrary};
+
+css_colors! {
+    ///The Material Design 2014 color palette, e.g. `material(deep-purple-a200)`
+    MaterialColors("material") {
+        Black, "black", (0, 0, 0);
+        White, "white", (255, 255, 255);
+        Red50, "red-50", (255, 235, 238);
+        Red100, "red-100", (255, 205, 210);
+        Red200, "red-200", (239, 154, 154);
+        Red300, "red-300", (229, 115, 115);
+        Red400, "red-400", (239, 83, 80);
+        Red500, "red-500", (244, 67, 54);
+        Red600, "red-600", (229, 57, 53);
+        Red700, "red-700", (211, 47, 47);
+        Red800, "red-800", (198, 40, 40);
+        Red900, "red-900", (183, 28, 28);
+        RedA100, "red-a100", (255, 138, 128);
+        RedA200, "red-a200", (255, 82, 82);
+        RedA400, "red-a400", (255, 23, 68);
+        RedA700, "red-a700", (213, 0, 0);
+        Pink50, "pink-50", (252, 228, 236);
+        Pink100, "pink-100", (248, 187, 208);
+        Pink200, "pink-200", (244, 143, 177);
+        Pink300, "pink-300", (240, 98, 146);
+        Pink400, "pink-400", (236, 64, 122);
+        Pink500, "pink-500", (233, 30, 99);
+        Pink600, "pink-600", (216, 27, 96);
+        Pink700, "pink-700", (194, 24, 91);
+        Pink800, "pink-800", (173, 20, 87);
+        Pink900, "pink-900", (136, 14, 79);
+        PinkA100, "pink-a100", (255, 128, 171);
+        PinkA200, "pink-a200", (255, 64, 129);
+        PinkA400, "pink-a400", (245, 0, 87);
+        PinkA700, "pink-a700", (197, 17, 98);
+        Purple50, "purple-50", (243, 229, 245);
+        Purple100, "purple-100", (225, 190, 231);
+        Purple200, "purple-200", (206, 147, 216);
+        Purple300, "purple-300", (186, 104, 200);
+        Purple400, "purple-400", (171, 71, 188);
+        Purple500, "purple-500", (156, 39, 176);
+        Purple600, "purple-600", (142, 36, 170);
+        Purple700, "purple-700", (123, 31, 162);
+        Purple800, "purple-800", (106, 27, 154);
+        Purple900, "purple-900", (74, 20, 140);
+        PurpleA100, "purple-a100", (234, 128, 252);
+        PurpleA200, "purple-a200", (224, 64, 251);
+        PurpleA400, "purple-a400", (213, 0, 249);
+        PurpleA700, "purple-a700", (170, 0, 255);
+        DeepPurple50, "deep-purple-50", (237, 231, 246);
+        DeepPurple100, "deep-purple-100", (209, 196, 233);
+        DeepPurple200, "deep-purple-200", (179, 157, 219);
+        DeepPurple300, "deep-purple-300", (149, 117, 205);
+        DeepPurple400, "deep-purple-400", (126, 87, 194);
+        DeepPurple500, "deep-purple-500", (103, 58, 183);
+        DeepPurple600, "deep-purple-600", (94, 53, 177);
+        DeepPurple700, "deep-purple-700", (81, 45, 168);
+        DeepPurple800, "deep-purple-800", (69, 39, 160);
+        DeepPurple900, "deep-purple-900", (49, 27, 146);
+        DeepPurpleA100, "deep-purple-a100", (179, 136, 255);
+        DeepPurpleA200, "deep-purple-a200", (124, 77, 255);
+        DeepPurpleA400, "deep-purple-a400", (101, 31, 255);
+        DeepPurpleA700, "deep-purple-a700", (98, 0, 234);
+        Indigo50, "indigo-50", (232, 234, 246);
+        Indigo100, "indigo-100", (197, 202, 233);
+        Indigo200, "indigo-200", (159, 168, 218);
+        Indigo300, "indigo-300", (121, 134, 203);
+        Indigo400, "indigo-400", (92, 107, 192);
+        Indigo500, "indigo-500", (63, 81, 181);
+        Indigo600, "indigo-600", (57, 73, 171);
+        Indigo700, "indigo-700", (48, 63, 159);
+        Indigo800, "indigo-800", (40, 53, 147);
+        Indigo900, "indigo-900", (26, 35, 126);
+        IndigoA100, "indigo-a100", (140, 158, 255);
+        IndigoA200, "indigo-a200", (83, 109, 254);
+        IndigoA400, "indigo-a400", (61, 90, 254);
+        IndigoA700, "indigo-a700", (48, 79, 254);
+        Blue50, "blue-50", (227, 242, 253);
+        Blue100, "blue-100", (187, 222, 251);
+        Blue200, "blue-200", (144, 202, 249);
+        Blue300, "blue-300", (100, 181, 246);
+        Blue400, "blue-400", (66, 165, 245);
+        Blue500, "blue-500", (33, 150, 243);
+        Blue600, "blue-600", (30, 136, 229);
+        Blue700, "blue-700", (25, 118, 210);
+        Blue800, "blue-800", (21, 101, 192);
+        Blue900, "blue-900", (13, 71, 161);
+        BlueA100, "blue-a100", (130, 177, 255);
+        BlueA200, "blue-a200", (68, 138, 255);
+        BlueA400, "blue-a400", (41, 121, 255);
+        BlueA700, "blue-a700", (41, 98, 255);
+        LightBlue50, "light-blue-50", (225, 245, 254);
+        LightBlue100, "light-blue-100", (179, 229, 252);
+        LightBlue200, "light-blue-200", (129, 212, 250);
+        LightBlue300, "light-blue-300", (79, 195, 247);
+        LightBlue400, "light-blue-400", (41, 182, 246);
+        LightBlue500, "light-blue-500", (3, 169, 244);
+        LightBlue600, "light-blue-600", (3, 155, 229);
+        LightBlue700, "light-blue-700", (2, 136, 209);
+        LightBlue800, "light-blue-800", (2, 119, 189);
+        LightBlue900, "light-blue-900", (1, 87, 155);
+        LightBlueA100, "light-blue-a100", (128, 216, 255);
+        LightBlueA200, "light-blue-a200", (64, 196, 255);
+        LightBlueA400, "light-blue-a400", (0, 176, 255);
+        LightBlueA700, "light-blue-a700", (0, 145, 234);
+        Cyan50, "cyan-50", (224, 247, 250);
+        Cyan100, "cyan-100", (178, 235, 242);
+        Cyan200, "cyan-200", (128, 222, 234);
+        Cyan300, "cyan-300", (77, 208, 225);
+        Cyan400, "cyan-400", (38, 198, 218);
+        Cyan500, "cyan-500", (0, 188, 212);
+        Cyan600, "cyan-600", (0, 172, 193);
+        Cyan700, "cyan-700", (0, 151, 167);
+        Cyan800, "cyan-800", (0, 131, 143);
+        Cyan900, "cyan-900", (0, 96, 100);
+        CyanA100, "cyan-a100", (132, 255, 255);
+        CyanA200, "cyan-a200", (24, 255, 255);
+        CyanA400, "cyan-a400", (0, 229, 255);
+        CyanA700, "cyan-a700", (0, 184, 212);
+        Teal50, "teal-50", (224, 242, 241);
+        Teal100, "teal-100", (178, 223, 219);
+        Teal200, "teal-200", (128, 203, 196);
+        Teal300, "teal-300", (77, 182, 172);
+        Teal400, "teal-400", (38, 166, 154);
+        Teal500, "teal-500", (0, 150, 136);
+        Teal600, "teal-600", (0, 137, 123);
+        Teal700, "teal-700", (0, 121, 107);
+        Teal800, "teal-800", (0, 105, 92);
+        Teal900, "teal-900", (0, 77, 64);
+        TealA100, "teal-a100", (167, 255, 235);
+        TealA200, "teal-a200", (100, 255, 218);
+        TealA400, "teal-a400", (29, 233, 182);
+        TealA700, "teal-a700", (0, 191, 165);
+        Green50, "green-50", (232, 245, 233);
+        Green100, "green-100", (200, 230, 201);
+        Green200, "green-200", (165, 214, 167);
+        Green300, "green-300", (129, 199, 132);
+        Green400, "green-400", (102, 187, 106);
+        Green500, "green-500", (76, 175, 80);
+        Green600, "green-600", (67, 160, 71);
+        Green700, "green-700", (56, 142, 60);
+        Green800, "green-800", (46, 125, 50);
+        Green900, "green-900", (27, 94, 32);
+        GreenA100, "green-a100", (185, 246, 202);
+        GreenA200, "green-a200", (105, 240, 174);
+        GreenA400, "green-a400", (0, 230, 118);
+        GreenA700, "green-a700", (0, 200, 83);
+        LightGreen50, "light-green-50", (241, 248, 233);
+        LightGreen100, "light-green-100", (220, 237, 200);
+        LightGreen200, "light-green-200", (197, 225, 165);
+        LightGreen300, "light-green-300", (174, 213, 129);
+        LightGreen400, "light-green-400", (156, 204, 101);
+        LightGreen500, "light-green-500", (139, 195, 74);
+        LightGreen600, "light-green-600", (124, 179, 66);
+        LightGreen700, "light-green-700", (104, 159, 56);
+        LightGreen800, "light-green-800", (85, 139, 47);
+        LightGreen900, "light-green-900", (51, 105, 30);
+        LightGreenA100, "light-green-a100", (204, 255, 144);
+        LightGreenA200, "light-green-a200", (178, 255, 89);
+        LightGreenA400, "light-green-a400", (118, 255, 3);
+        LightGreenA700, "light-green-a700", (100, 221, 23);
+        Lime50, "lime-50", (249, 251, 231);
+        Lime100, "lime-100", (240, 244, 195);
+        Lime200, "lime-200", (230, 238, 156);
+        Lime300, "lime-300", (220, 231, 117);
+        Lime400, "lime-400", (212, 225, 87);
+        Lime500, "lime-500", (205, 220, 57);
+        Lime600, "lime-600", (192, 202, 51);
+        Lime700, "lime-700", (175, 180, 43);
+        Lime800, "lime-800", (158, 157, 36);
+        Lime900, "lime-900", (130, 119, 23);
+        LimeA100, "lime-a100", (244, 255, 129);
+        LimeA200, "lime-a200", (238, 255, 65);
+        LimeA400, "lime-a400", (198, 255, 0);
+        LimeA700, "lime-a700", (174, 234, 0);
+        Yellow50, "yellow-50", (255, 253, 231);
+        Yellow100, "yellow-100", (255, 249, 196);
+        Yellow200, "yellow-200", (255, 245, 157);
+        Yellow300, "yellow-300", (255, 241, 118);
+        Yellow400, "yellow-400", (255, 238, 88);
+        Yellow500, "yellow-500", (255, 235, 59);
+        Yellow600, "yellow-600", (253, 216, 53);
+        Yellow700, "yellow-700", (251, 192, 45);
+        Yellow800, "yellow-800", (249, 168, 37);
+        Yellow900, "yellow-900", (245, 127, 23);
+        YellowA100, "yellow-a100", (255, 255, 141);
+        YellowA200, "yellow-a200", (255, 255, 0);
+        YellowA400, "yellow-a400", (255, 234, 0);
+        YellowA700, "yellow-a700", (255, 214, 0);
+        Amber50, "amber-50", (255, 248, 225);
+        Amber100, "amber-100", (255, 236, 179);
+        Amber200, "amber-200", (255, 224, 130);
+        Amber300, "amber-300", (255, 213, 79);
+        Amber400, "amber-400", (255, 202, 40);
+        Amber500, "amber-500", (255, 193, 7);
+        Amber600, "amber-600", (255, 179, 0);
+        Amber700, "amber-700", (255, 160, 0);
+        Amber800, "amber-800", (255, 143, 0);
+        Amber900, "amber-900", (255, 111, 0);
+        AmberA100, "amber-a100", (255, 229, 127);
+        AmberA200, "amber-a200", (255, 215, 64);
+        AmberA400, "amber-a400", (255, 196, 0);
+        AmberA700, "amber-a700", (255, 171, 0);
+        Orange50, "orange-50", (255, 243, 224);
+        Orange100, "orange-100", (255, 224, 178);
+        Orange200, "orange-200", (255, 204, 128);
+        Orange300, "orange-300", (255, 183, 77);
+        Orange400, "orange-400", (255, 167, 38);
+        Orange500, "orange-500", (255, 152, 0);
+        Orange600, "orange-600", (251, 140, 0);
+        Orange700, "orange-700", (245, 124, 0);
+        Orange800, "orange-800", (239, 108, 0);
+        Orange900, "orange-900", (230, 81, 0);
+        OrangeA100, "orange-a100", (255, 209, 128);
+        OrangeA200, "orange-a200", (255, 171, 64);
+        OrangeA400, "orange-a400", (255, 145, 0);
+        OrangeA700, "orange-a700", (255, 109, 0);
+        DeepOrange50, "deep-orange-50", (251, 233, 231);
+        DeepOrange100, "deep-orange-100", (255, 204, 188);
+        DeepOrange200, "deep-orange-200", (255, 171, 145);
+        DeepOrange300, "deep-orange-300", (255, 138, 101);
+        DeepOrange400, "deep-orange-400", (255, 112, 67);
+        DeepOrange500, "deep-orange-500", (255, 87, 34);
+        DeepOrange600, "deep-orange-600", (244, 81, 30);
+        DeepOrange700, "deep-orange-700", (230, 74, 25);
+        DeepOrange800, "deep-orange-800", (216, 67, 21);
+        DeepOrange900, "deep-orange-900", (191, 54, 12);
+        DeepOrangeA100, "deep-orange-a100", (255, 158, 128);
+        DeepOrangeA200, "deep-orange-a200", (255, 110, 64);
+        DeepOrangeA400, "deep-orange-a400", (255, 61, 0);
+        DeepOrangeA700, "deep-orange-a700", (221, 44, 0);
+        Brown50, "brown-50", (239, 235, 233);
+        Brown100, "brown-100", (215, 204, 200);
+        Brown200, "brown-200", (188, 170, 164);
+        Brown300, "brown-300", (161, 136, 127);
+        Brown400, "brown-400", (141, 110, 99);
+        Brown500, "brown-500", (121, 85, 72);
+        Brown600, "brown-600", (109, 76, 65);
+        Brown700, "brown-700", (93, 64, 55);
+        Brown800, "brown-800", (78, 52, 46);
+        Brown900, "brown-900", (62, 39, 35);
+        Grey50, "grey-50", (250, 250, 250);
+        Grey100, "grey-100", (245, 245, 245);
+        Grey200, "grey-200", (238, 238, 238);
+        Grey300, "grey-300", (224, 224, 224);
+        Grey400, "grey-400", (189, 189, 189);
+        Grey500, "grey-500", (158, 158, 158);
+        Grey600, "grey-600", (117, 117, 117);
+        Grey700, "grey-700", (97, 97, 97);
+        Grey800, "grey-800", (66, 66, 66);
+        Grey900, "grey-900", (33, 33, 33);
+        BlueGrey50, "blue-grey-50", (236, 239, 241);
+        BlueGrey100, "blue-grey-100", (207, 216, 220);
+        BlueGrey200, "blue-grey-200", (176, 190, 197);
+        BlueGrey300, "blue-grey-300", (144, 164, 174);
+        BlueGrey400, "blue-grey-400", (120, 144, 156);
+        BlueGrey500, "blue-grey-500", (96, 125, 139);
+        BlueGrey600, "blue-grey-600", (84, 110, 122);
+        BlueGrey700, "blue-grey-700", (69, 90, 100);
+        BlueGrey800, "blue-grey-800", (55, 71, 79);
+        BlueGrey900, "blue-grey-900", (38, 50, 56);
+    }
+}
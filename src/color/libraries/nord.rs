@@ -0,0 +1,32 @@
+use super::css::css_colors;
+use super::{ColorLibrary, EnumerableLibrary};
+
+css_colors! {
+    ///The Nord color palette, e.g. `nord(nord8)`. The aurora colors can also be
+    ///referenced by their color, e.g. `nord(red)`.
+    NordColors("nord") {
+        Nord0, "nord0", (46, 52, 64);
+        Nord1, "nord1", (59, 66, 82);
+        Nord2, "nord2", (67, 76, 94);
+        Nord3, "nord3", (76, 86, 106);
+        Nord4, "nord4", (216, 222, 233);
+        Nord5, "nord5", (229, 233, 240);
+        Nord6, "nord6", (236, 239, 244);
+        Nord7, "nord7", (143, 188, 187);
+        Nord8, "nord8", (136, 192, 208);
+        Nord9, "nord9", (129, 161, 193);
+        Nord10, "nord10", (94, 129, 172);
+        Nord11, "nord11", (191, 97, 106);
+        Nord12, "nord12", (208, 135, 112);
+        Nord13, "nord13", (235, 203, 139);
+        Nord14, "nord14", (163, 190, 140);
+        Nord15, "nord15", (180, 142, 173);
+    }
+    aliases {
+        "red" => "nord11";
+        "orange" => "nord12";
+        "yellow" => "nord13";
+        "green" => "nord14";
+        "purple" => "nord15";
+    }
+}
@@ -0,0 +1,24 @@
+use super::css::css_colors;
+use super::{ColorLibrary, EnumerableLibrary};
+
+css_colors! {
+    ///The Solarized color palette, e.g. `solarized(base03)`
+    SolarizedColors("solarized") {
+        Base03, "base03", (0, 43, 54);
+        Base02, "base02", (7, 54, 66);
+        Base01, "base01", (88, 110, 117);
+        Base00, "base00", (101, 123, 131);
+        Base0, "base0", (131, 148, 150);
+        Base1, "base1", (147, 161, 161);
+        Base2, "base2", (238, 232, 213);
+        Base3, "base3", (253, 246, 227);
+        Yellow, "yellow", (181, 137, 0);
+        Orange, "orange", (203, 75, 22);
+        Red, "red", (220, 50, 47);
+        Magenta, "magenta", (211, 54, 130);
+        Violet, "violet", (108, 113, 196);
+        Blue, "blue", (38, 139, 210);
+        Cyan, "cyan", (42, 161, 152);
+        Green, "green", (133, 153, 0);
+    }
+}
@@ -0,0 +1,252 @@
+use super::css::css_colors;
+use super::{ColorLibrary, EnumerableLibrary};
+
+css_colors! {
+    ///The Tailwind CSS color palette, e.g. `tailwind(blue-500)`
+    TailwindColors("tailwind") {
+        Black, "black", (0, 0, 0);
+        White, "white", (255, 255, 255);
+        Slate50, "slate-50", (248, 250, 252);
+        Slate100, "slate-100", (241, 245, 249);
+        Slate200, "slate-200", (226, 232, 240);
+        Slate300, "slate-300", (203, 213, 225);
+        Slate400, "slate-400", (148, 163, 184);
+        Slate500, "slate-500", (100, 116, 139);
+        Slate600, "slate-600", (71, 85, 105);
+        Slate700, "slate-700", (51, 65, 85);
+        Slate800, "slate-800", (30, 41, 59);
+        Slate900, "slate-900", (15, 23, 42);
+        Slate950, "slate-950", (2, 6, 23);
+        Gray50, "gray-50", (249, 250, 251);
+        Gray100, "gray-100", (243, 244, 246);
+        Gray200, "gray-200", (229, 231, 235);
+        Gray300, "gray-300", (209, 213, 219);
+        Gray400, "gray-400", (156, 163, 175);
+        Gray500, "gray-500", (107, 114, 128);
+        Gray600, "gray-600", (75, 85, 99);
+        Gray700, "gray-700", (55, 65, 81);
+        Gray800, "gray-800", (31, 41, 55);
+        Gray900, "gray-900", (17, 24, 39);
+        Gray950, "gray-950", (3, 7, 18);
+        Zinc50, "zinc-50", (250, 250, 250);
+        Zinc100, "zinc-100", (244, 244, 245);
+        Zinc200, "zinc-200", (228, 228, 231);
+        Zinc300, "zinc-300", (212, 212, 216);
+        Zinc400, "zinc-400", (161, 161, 170);
+        Zinc500, "zinc-500", (113, 113, 122);
+        Zinc600, "zinc-600", (82, 82, 91);
+        Zinc700, "zinc-700", (63, 63, 70);
+        Zinc800, "zinc-800", (39, 39, 42);
+        Zinc900, "zinc-900", (24, 24, 27);
+        Zinc950, "zinc-950", (9, 9, 11);
+        Neutral50, "neutral-50", (250, 250, 250);
+        Neutral100, "neutral-100", (245, 245, 245);
+        Neutral200, "neutral-200", (229, 229, 229);
+        Neutral300, "neutral-300", (212, 212, 212);
+        Neutral400, "neutral-400", (163, 163, 163);
+        Neutral500, "neutral-500", (115, 115, 115);
+        Neutral600, "neutral-600", (82, 82, 82);
+        Neutral700, "neutral-700", (64, 64, 64);
+        Neutral800, "neutral-800", (38, 38, 38);
+        Neutral900, "neutral-900", (23, 23, 23);
+        Neutral950, "neutral-950", (10, 10, 10);
+        Stone50, "stone-50", (250, 250, 249);
+        Stone100, "stone-100", (245, 245, 244);
+        Stone200, "stone-200", (231, 229, 228);
+        Stone300, "stone-300", (214, 211, 209);
+        Stone400, "stone-400", (168, 162, 158);
+        Stone500, "stone-500", (120, 113, 108);
+        Stone600, "stone-600", (87, 83, 78);
+        Stone700, "stone-700", (68, 64, 60);
+        Stone800, "stone-800", (41, 37, 36);
+        Stone900, "stone-900", (28, 25, 23);
+        Stone950, "stone-950", (12, 10, 9);
+        Red50, "red-50", (254, 242, 242);
+        Red100, "red-100", (254, 226, 226);
+        Red200, "red-200", (254, 202, 202);
+        Red300, "red-300", (252, 165, 165);
+        Red400, "red-400", (248, 113, 113);
+        Red500, "red-500", (239, 68, 68);
+        Red600, "red-600", (220, 38, 38);
+        Red700, "red-700", (185, 28, 28);
+        Red800, "red-800", (153, 27, 27);
+        Red900, "red-900", (127, 29, 29);
+        Red950, "red-950", (69, 10, 10);
+        Orange50, "orange-50", (255, 247, 237);
+        Orange100, "orange-100", (255, 237, 213);
+        Orange200, "orange-200", (254, 215, 170);
+        Orange300, "orange-300", (253, 186, 116);
+        Orange400, "orange-400", (251, 146, 60);
+        Orange500, "orange-500", (249, 115, 22);
+        Orange600, "orange-600", (234, 88, 12);
+        Orange700, "orange-700", (194, 65, 12);
+        Orange800, "orange-800", (154, 52, 18);
+        Orange900, "orange-900", (124, 45, 18);
+        Orange950, "orange-950", (67, 20, 7);
+        Amber50, "amber-50", (255, 251, 235);
+        Amber100, "amber-100", (254, 243, 199);
+        Amber200, "amber-200", (253, 230, 138);
+        Amber300, "amber-300", (252, 211, 77);
+        Amber400, "amber-400", (251, 191, 36);
+        Amber500, "amber-500", (245, 158, 11);
+        Amber600, "amber-600", (217, 119, 6);
+        Amber700, "amber-700", (180, 83, 9);
+        Amber800, "amber-800", (146, 64, 14);
+        Amber900, "amber-900", (120, 53, 15);
+        Amber950, "amber-950", (69, 26, 3);
+        Yellow50, "yellow-50", (254, 252, 232);
+        Yellow100, "yellow-100", (254, 249, 195);
+        Yellow200, "yellow-200", (254, 240, 138);
+        Yellow300, "yellow-300", (253, 224, 71);
+        Yellow400, "yellow-400", (250, 204, 21);
+        Yellow500, "yellow-500", (234, 179, 8);
+        Yellow600, "yellow-600", (202, 138, 4);
+        Yellow700, "yellow-700", (161, 98, 7);
+        Yellow800, "yellow-800", (133, 77, 14);
+        Yellow900, "yellow-900", (113, 63, 18);
+        Yellow950, "yellow-950", (66, 32, 6);
+        Lime50, "lime-50", (247, 254, 231);
+        Lime100, "lime-100", (236, 252, 203);
+        Lime200, "lime-200", (217, 249, 157);
+        Lime300, "lime-300", (190, 242, 100);
+        Lime400, "lime-400", (163, 230, 53);
+        Lime500, "lime-500", (132, 204, 22);
+        Lime600, "lime-600", (101, 163, 13);
+        Lime700, "lime-700", (77, 124, 15);
+        Lime800, "lime-800", (63, 98, 18);
+        Lime900, "lime-900", (54, 83, 20);
+        Lime950, "lime-950", (26, 46, 5);
+        Green50, "green-50", (240, 253, 244);
+        Green100, "green-100", (220, 252, 231);
+        Green200, "green-200", (187, 247, 208);
+        Green300, "green-300", (134, 239, 172);
+        Green400, "green-400", (74, 222, 128);
+        Green500, "green-500", (34, 197, 94);
+        Green600, "green-600", (22, 163, 74);
+        Green700, "green-700", (21, 128, 61);
+        Green800, "green-800", (22, 101, 52);
+        Green900, "green-900", (20, 83, 45);
+        Green950, "green-950", (5, 46, 22);
+        Emerald50, "emerald-50", (236, 253, 245);
+        Emerald100, "emerald-100", (209, 250, 229);
+        Emerald200, "emerald-200", (167, 243, 208);
+        Emerald300, "emerald-300", (110, 231, 183);
+        Emerald400, "emerald-400", (52, 211, 153);
+        Emerald500, "emerald-500", (16, 185, 129);
+        Emerald600, "emerald-600", (5, 150, 105);
+        Emerald700, "emerald-700", (4, 120, 87);
+        Emerald800, "emerald-800", (6, 95, 70);
+        Emerald900, "emerald-900", (6, 78, 59);
+        Emerald950, "emerald-950", (2, 44, 34);
+        Teal50, "teal-50", (240, 253, 250);
+        Teal100, "teal-100", (204, 251, 241);
+        Teal200, "teal-200", (153, 246, 228);
+        Teal300, "teal-300", (94, 234, 212);
+        Teal400, "teal-400", (45, 212, 191);
+        Teal500, "teal-500", (20, 184, 166);
+        Teal600, "teal-600", (13, 148, 136);
+        Teal700, "teal-700", (15, 118, 110);
+        Teal800, "teal-800", (17, 94, 89);
+        Teal900, "teal-900", (19, 78, 74);
+        Teal950, "teal-950", (4, 47, 46);
+        Cyan50, "cyan-50", (236, 254, 255);
+        Cyan100, "cyan-100", (207, 250, 254);
+        Cyan200, "cyan-200", (165, 243, 252);
+        Cyan300, "cyan-300", (103, 232, 249);
+        Cyan400, "cyan-400", (34, 211, 238);
+        Cyan500, "cyan-500", (6, 182, 212);
+        Cyan600, "cyan-600", (8, 145, 178);
+        Cyan700, "cyan-700", (14, 116, 144);
+        Cyan800, "cyan-800", (21, 94, 117);
+        Cyan900, "cyan-900", (22, 78, 99);
+        Cyan950, "cyan-950", (8, 51, 68);
+        Sky50, "sky-50", (240, 249, 255);
+        Sky100, "sky-100", (224, 242, 254);
+        Sky200, "sky-200", (186, 230, 253);
+        Sky300, "sky-300", (125, 211, 252);
+        Sky400, "sky-400", (56, 189, 248);
+        Sky500, "sky-500", (14, 165, 233);
+        Sky600, "sky-600", (2, 132, 199);
+        Sky700, "sky-700", (3, 105, 161);
+        Sky800, "sky-800", (7, 89, 133);
+        Sky900, "sky-900", (12, 74, 110);
+        Sky950, "sky-950", (8, 47, 73);
+        Blue50, "blue-50", (239, 246, 255);
+        Blue100, "blue-100", (219, 234, 254);
+        Blue200, "blue-200", (191, 219, 254);
+        Blue300, "blue-300", (147, 197, 253);
+        Blue400, "blue-400", (96, 165, 250);
+        Blue500, "blue-500", (59, 130, 246);
+        Blue600, "blue-600", (37, 99, 235);
+        Blue700, "blue-700", (29, 78, 216);
+        Blue800, "blue-800", (30, 64, 175);
+        Blue900, "blue-900", (30, 58, 138);
+        Blue950, "blue-950", (23, 37, 84);
+        Indigo50, "indigo-50", (238, 242, 255);
+        Indigo100, "indigo-100", (224, 231, 255);
+        Indigo200, "indigo-200", (199, 210, 254);
+        Indigo300, "indigo-300", (165, 180, 252);
+        Indigo400, "indigo-400", (129, 140, 248);
+        Indigo500, "indigo-500", (99, 102, 241);
+        Indigo600, "indigo-600", (79, 70, 229);
+        Indigo700, "indigo-700", (67, 56, 202);
+        Indigo800, "indigo-800", (55, 48, 163);
+        Indigo900, "indigo-900", (49, 46, 129);
+        Indigo950, "indigo-950", (30, 27, 75);
+        Violet50, "violet-50", (245, 243, 255);
+        Violet100, "violet-100", (237, 233, 254);
+        Violet200, "violet-200", (221, 214, 254);
+        Violet300, "violet-300", (196, 181, 253);
+        Violet400, "violet-400", (167, 139, 250);
+        Violet500, "violet-500", (139, 92, 246);
+        Violet600, "violet-600", (124, 58, 237);
+        Violet700, "violet-700", (109, 40, 217);
+        Violet800, "violet-800", (91, 33, 182);
+        Violet900, "violet-900", (76, 29, 149);
+        Violet950, "violet-950", (46, 16, 101);
+        Purple50, "purple-50", (250, 245, 255);
+        Purple100, "purple-100", (243, 232, 255);
+        Purple200, "purple-200", (233, 213, 255);
+        Purple300, "purple-300", (216, 180, 254);
+        Purple400, "purple-400", (192, 132, 252);
+        Purple500, "purple-500", (168, 85, 247);
+        Purple600, "purple-600", (147, 51, 234);
+        Purple700, "purple-700", (126, 34, 206);
+        Purple800, "purple-800", (107, 33, 168);
+        Purple900, "purple-900", (88, 28, 135);
+        Purple950, "purple-950", (59, 7, 100);
+        Fuchsia50, "fuchsia-50", (253, 244, 255);
+        Fuchsia100, "fuchsia-100", (250, 232, 255);
+        Fuchsia200, "fuchsia-200", (245, 208, 254);
+        Fuchsia300, "fuchsia-300", (240, 171, 252);
+        Fuchsia400, "fuchsia-400", (232, 121, 249);
+        Fuchsia500, "fuchsia-500", (217, 70, 239);
+        Fuchsia600, "fuchsia-600", (192, 38, 211);
+        Fuchsia700, "fuchsia-700", (162, 28, 175);
+        Fuchsia800, "fuchsia-800", (134, 25, 143);
+        Fuchsia900, "fuchsia-900", (112, 26, 117);
+        Fuchsia950, "fuchsia-950", (74, 4, 78);
+        Pink50, "pink-50", (253, 242, 248);
+        Pink100, "pink-100", (252, 231, 243);
+        Pink200, "pink-200", (251, 207, 232);
+        Pink300, "pink-300", (249, 168, 212);
+        Pink400, "pink-400", (244, 114, 182);
+        Pink500, "pink-500", (236, 72, 153);
+        Pink600, "pink-600", (219, 39, 119);
+        Pink700, "pink-700", (190, 24, 93);
+        Pink800, "pink-800", (157, 23, 77);
+        Pink900, "pink-900", (131, 24, 67);
+        Pink950, "pink-950", (80, 7, 36);
+        Rose50, "rose-50", (255, 241, 242);
+        Rose100, "rose-100", (255, 228, 230);
+        Rose200, "rose-200", (254, 205, 211);
+        Rose300, "rose-300", (253, 164, 175);
+        Rose400, "rose-400", (251, 113, 133);
+        Rose500, "rose-500", (244, 63, 94);
+        Rose600, "rose-600", (225, 29, 72);
+        Rose700, "rose-700", (190, 18, 60);
+        Rose800, "rose-800", (159, 18, 57);
+        Rose900, "rose-900", (136, 19, 55);
+        Rose950, "rose-950", (76, 5, 25);
+    }
+}
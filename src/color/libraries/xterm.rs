@@ -1,31 +1,39 @@
-use super::ColorLibrary;
+use super::{ColorLibrary, EnumerableLibrary};
 
 macro_rules! xterm_colors {
     (
-        $enum_name:ident {
+        $(#[$meta:meta])*
+        $enum_name:ident($wrapper:literal) {
             $($variant_name:ident, $xterm_name:expr, $color_number:expr, ($r:expr, $g:expr, $b:expr));* $(;)?
         }
+        $(aliases {
+            $($alias:expr => $target:expr);* $(;)?
+        })?
     ) => {
-        ///List of ANSI256 colors by name
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
         pub enum $enum_name {
             $(
+                #[doc = $xterm_name]
                 $variant_name,
             )*
         }
 
         impl ColorLibrary for $enum_name {
-            const WRAPPER: &str="xterm()";
+            const WRAPPER: &str = concat!($wrapper, "()");
+
+            const ALIASES: &'static [(&'static str, &'static str)] = &[$($(($alias, $target),)*)?];
 
             fn wrap_name(str: &str) -> String {
-                let wrapped_str = format!("xterm({})", str);
+                let wrapped_str = format!(concat!($wrapper, "({})"), str);
                 return wrapped_str;
-}
+            }
 
             fn unwrap_name(str: &str) -> &str {
-                if str.len() < "xterm()".len() {
+                if str.len() < Self::WRAPPER.len() {
                     return str;
                 }
-                return &str[6..str.len() - 1];
+                return &str[$wrapper.len() + 1..str.len() - 1];
             }
 
             fn color_name(&self) -> &'static str {
@@ -45,6 +53,31 @@ macro_rules! xterm_colors {
             }
 
             fn get_name(name: &str) -> Option<Self> {
+                static NAMES: std::sync::OnceLock<std::collections::HashMap<String, $enum_name>> =
+                    std::sync::OnceLock::new();
+                Self::get_exact_name(name).or_else(|| {
+                    NAMES
+                        .get_or_init(super::normalized_names)
+                        .get(&super::normalize_name(name))
+                        .copied()
+                })
+            }
+
+            fn nearest_with(rgb: &super::Rgb, metric: super::DistanceMetric) -> Option<(Self, f64)> {
+                super::find_nearest(rgb, metric)
+            }
+
+            fn exact_matches(rgb: &super::Rgb) -> Vec<Self> {
+                super::find_exact_matches(rgb)
+            }
+        }
+
+        impl EnumerableLibrary for $enum_name {
+            const ALL: &'static [Self] = &[$(Self::$variant_name,)*];
+
+            fn get_exact_name(name: &str) -> Option<Self> {
+                //Libraries may repeat a name, the first color with it is returned
+                #[allow(unreachable_patterns)]
                 match name {
                     $(
                         $xterm_name => Some(Self::$variant_name),
@@ -52,12 +85,10 @@ macro_rules! xterm_colors {
                     _ => None
                 }
             }
-
-
         }
 
         impl $enum_name {
-
+            ///Get the ANSI256 index of the color
             pub fn ansi256(&self) -> u8 {
                 match self {
                     $(
@@ -65,6 +96,8 @@ macro_rules! xterm_colors {
                     )*
                 }
             }
+
+            ///Get the color at an ANSI256 index
             pub fn get_ansi256(ansi256: u8) -> Self {
                 match ansi256 {
                     $(
@@ -76,8 +109,11 @@ macro_rules! xterm_colors {
     };
 }
 
+pub(super) use xterm_colors;
+
 xterm_colors! {
-    XtermColors {
+    ///List of ANSI256 colors by name
+    XtermColors("xterm") {
         Black ,  "Black" ,  0 ,  (0 , 0 , 0);
         Maroon ,  "Maroon" ,  1 ,  (128 , 0 , 0);
         Green ,  "Green" ,  2 ,  (0 , 128 , 0);
@@ -336,4 +372,7 @@ xterm_colors! {
         Grey93 ,  "Grey93" ,  255 ,  (238 , 238 , 238);
 
     }
+    aliases {
+        "LightLavender" => "LightLavendar";
+    }
 }
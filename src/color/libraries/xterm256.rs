@@ -0,0 +1,268 @@
+use super::xterm::xterm_colors;
+use super::{ColorLibrary, EnumerableLibrary};
+
+xterm_colors! {
+    ///The widely used names for the ANSI256 palette, as used by tools such as rich and
+    ///ncurses-based color pickers. Several colors share a name, in which case the variant of every
+    ///color after the first is suffixed with its index, and looking up the name gives the lowest
+    ///index.
+    #[allow(non_camel_case_types)]
+    Xterm256Colors("xterm256") {
+        Black, "Black", 0, (0, 0, 0);
+        Maroon, "Maroon", 1, (128, 0, 0);
+        Green, "Green", 2, (0, 128, 0);
+        Olive, "Olive", 3, (128, 128, 0);
+        Navy, "Navy", 4, (0, 0, 128);
+        Purple, "Purple", 5, (128, 0, 128);
+        Teal, "Teal", 6, (0, 128, 128);
+        Silver, "Silver", 7, (192, 192, 192);
+        Grey, "Grey", 8, (128, 128, 128);
+        Red, "Red", 9, (255, 0, 0);
+        Lime, "Lime", 10, (0, 255, 0);
+        Yellow, "Yellow", 11, (255, 255, 0);
+        Blue, "Blue", 12, (0, 0, 255);
+        Fuchsia, "Fuchsia", 13, (255, 0, 255);
+        Aqua, "Aqua", 14, (0, 255, 255);
+        White, "White", 15, (255, 255, 255);
+        Grey0, "Grey0", 16, (0, 0, 0);
+        NavyBlue, "NavyBlue", 17, (0, 0, 95);
+        DarkBlue, "DarkBlue", 18, (0, 0, 135);
+        Blue3, "Blue3", 19, (0, 0, 175);
+        Blue3_20, "Blue3", 20, (0, 0, 215);
+        Blue1, "Blue1", 21, (0, 0, 255);
+        DarkGreen, "DarkGreen", 22, (0, 95, 0);
+        DeepSkyBlue4, "DeepSkyBlue4", 23, (0, 95, 95);
+        DeepSkyBlue4_24, "DeepSkyBlue4", 24, (0, 95, 135);
+        DeepSkyBlue4_25, "DeepSkyBlue4", 25, (0, 95, 175);
+        DodgerBlue3, "DodgerBlue3", 26, (0, 95, 215);
+        DodgerBlue2, "DodgerBlue2", 27, (0, 95, 255);
+        Green4, "Green4", 28, (0, 135, 0);
+        SpringGreen4, "SpringGreen4", 29, (0, 135, 95);
+        Turquoise4, "Turquoise4", 30, (0, 135, 135);
+        DeepSkyBlue3, "DeepSkyBlue3", 31, (0, 135, 175);
+        DeepSkyBlue3_32, "DeepSkyBlue3", 32, (0, 135, 215);
+        DodgerBlue1, "DodgerBlue1", 33, (0, 135, 255);
+        Green3, "Green3", 34, (0, 175, 0);
+        SpringGreen3, "SpringGreen3", 35, (0, 175, 95);
+        DarkCyan, "DarkCyan", 36, (0, 175, 135);
+        LightSeaGreen, "LightSeaGreen", 37, (0, 175, 175);
+        DeepSkyBlue2, "DeepSkyBlue2", 38, (0, 175, 215);
+        DeepSkyBlue1, "DeepSkyBlue1", 39, (0, 175, 255);
+        Green3_40, "Green3", 40, (0, 215, 0);
+        SpringGreen3_41, "SpringGreen3", 41, (0, 215, 95);
+        SpringGreen2, "SpringGreen2", 42, (0, 215, 135);
+        Cyan3, "Cyan3", 43, (0, 215, 175);
+        DarkTurquoise, "DarkTurquoise", 44, (0, 215, 215);
+        Turquoise2, "Turquoise2", 45, (0, 215, 255);
+        Green1, "Green1", 46, (0, 255, 0);
+        SpringGreen2_47, "SpringGreen2", 47, (0, 255, 95);
+        SpringGreen1, "SpringGreen1", 48, (0, 255, 135);
+        MediumSpringGreen, "MediumSpringGreen", 49, (0, 255, 175);
+        Cyan2, "Cyan2", 50, (0, 255, 215);
+        Cyan1, "Cyan1", 51, (0, 255, 255);
+        DarkRed, "DarkRed", 52, (95, 0, 0);
+        DeepPink4, "DeepPink4", 53, (95, 0, 95);
+        Purple4, "Purple4", 54, (95, 0, 135);
+        Purple4_55, "Purple4", 55, (95, 0, 175);
+        Purple3, "Purple3", 56, (95, 0, 215);
+        BlueViolet, "BlueViolet", 57, (95, 0, 255);
+        Orange4, "Orange4", 58, (95, 95, 0);
+        Grey37, "Grey37", 59, (95, 95, 95);
+        MediumPurple4, "MediumPurple4", 60, (95, 95, 135);
+        SlateBlue3, "SlateBlue3", 61, (95, 95, 175);
+        SlateBlue3_62, "SlateBlue3", 62, (95, 95, 215);
+        RoyalBlue1, "RoyalBlue1", 63, (95, 95, 255);
+        Chartreuse4, "Chartreuse4", 64, (95, 135, 0);
+        DarkSeaGreen4, "DarkSeaGreen4", 65, (95, 135, 95);
+        PaleTurquoise4, "PaleTurquoise4", 66, (95, 135, 135);
+        SteelBlue, "SteelBlue", 67, (95, 135, 175);
+        SteelBlue3, "SteelBlue3", 68, (95, 135, 215);
+        CornflowerBlue, "CornflowerBlue", 69, (95, 135, 255);
+        Chartreuse3, "Chartreuse3", 70, (95, 175, 0);
+        DarkSeaGreen4_71, "DarkSeaGreen4", 71, (95, 175, 95);
+        CadetBlue, "CadetBlue", 72, (95, 175, 135);
+        CadetBlue_73, "CadetBlue", 73, (95, 175, 175);
+        SkyBlue3, "SkyBlue3", 74, (95, 175, 215);
+        SteelBlue1, "SteelBlue1", 75, (95, 175, 255);
+        Chartreuse3_76, "Chartreuse3", 76, (95, 215, 0);
+        PaleGreen3, "PaleGreen3", 77, (95, 215, 95);
+        SeaGreen3, "SeaGreen3", 78, (95, 215, 135);
+        Aquamarine3, "Aquamarine3", 79, (95, 215, 175);
+        MediumTurquoise, "MediumTurquoise", 80, (95, 215, 215);
+        SteelBlue1_81, "SteelBlue1", 81, (95, 215, 255);
+        Chartreuse2, "Chartreuse2", 82, (95, 255, 0);
+        SeaGreen2, "SeaGreen2", 83, (95, 255, 95);
+        SeaGreen1, "SeaGreen1", 84, (95, 255, 135);
+        SeaGreen1_85, "SeaGreen1", 85, (95, 255, 175);
+        Aquamarine1, "Aquamarine1", 86, (95, 255, 215);
+        DarkSlateGray2, "DarkSlateGray2", 87, (95, 255, 255);
+        DarkRed_88, "DarkRed", 88, (135, 0, 0);
+        DeepPink4_89, "DeepPink4", 89, (135, 0, 95);
+        DarkMagenta, "DarkMagenta", 90, (135, 0, 135);
+        DarkMagenta_91, "DarkMagenta", 91, (135, 0, 175);
+        DarkViolet, "DarkViolet", 92, (135, 0, 215);
+        Purple_93, "Purple", 93, (135, 0, 255);
+        Orange4_94, "Orange4", 94, (135, 95, 0);
+        LightPink4, "LightPink4", 95, (135, 95, 95);
+        Plum4, "Plum4", 96, (135, 95, 135);
+        MediumPurple3, "MediumPurple3", 97, (135, 95, 175);
+        MediumPurple3_98, "MediumPurple3", 98, (135, 95, 215);
+        SlateBlue1, "SlateBlue1", 99, (135, 95, 255);
+        Yellow4, "Yellow4", 100, (135, 135, 0);
+        Wheat4, "Wheat4", 101, (135, 135, 95);
+        Grey53, "Grey53", 102, (135, 135, 135);
+        LightSlateGrey, "LightSlateGrey", 103, (135, 135, 175);
+        MediumPurple, "MediumPurple", 104, (135, 135, 215);
+        LightSlateBlue, "LightSlateBlue", 105, (135, 135, 255);
+        Yellow4_106, "Yellow4", 106, (135, 175, 0);
+        DarkOliveGreen3, "DarkOliveGreen3", 107, (135, 175, 95);
+        DarkSeaGreen, "DarkSeaGreen", 108, (135, 175, 135);
+        LightSkyBlue3, "LightSkyBlue3", 109, (135, 175, 175);
+        LightSkyBlue3_110, "LightSkyBlue3", 110, (135, 175, 215);
+        SkyBlue2, "SkyBlue2", 111, (135, 175, 255);
+        Chartreuse2_112, "Chartreuse2", 112, (135, 215, 0);
+        DarkOliveGreen3_113, "DarkOliveGreen3", 113, (135, 215, 95);
+        PaleGreen3_114, "PaleGreen3", 114, (135, 215, 135);
+        DarkSeaGreen3, "DarkSeaGreen3", 115, (135, 215, 175);
+        DarkSlateGray3, "DarkSlateGray3", 116, (135, 215, 215);
+        SkyBlue1, "SkyBlue1", 117, (135, 215, 255);
+        Chartreuse1, "Chartreuse1", 118, (135, 255, 0);
+        LightGreen, "LightGreen", 119, (135, 255, 95);
+        LightGreen_120, "LightGreen", 120, (135, 255, 135);
+        PaleGreen1, "PaleGreen1", 121, (135, 255, 175);
+        Aquamarine1_122, "Aquamarine1", 122, (135, 255, 215);
+        DarkSlateGray1, "DarkSlateGray1", 123, (135, 255, 255);
+        Red3, "Red3", 124, (175, 0, 0);
+        DeepPink4_125, "DeepPink4", 125, (175, 0, 95);
+        MediumVioletRed, "MediumVioletRed", 126, (175, 0, 135);
+        Magenta3, "Magenta3", 127, (175, 0, 175);
+        DarkViolet_128, "DarkViolet", 128, (175, 0, 215);
+        Purple_129, "Purple", 129, (175, 0, 255);
+        DarkOrange3, "DarkOrange3", 130, (175, 95, 0);
+        IndianRed, "IndianRed", 131, (175, 95, 95);
+        HotPink3, "HotPink3", 132, (175, 95, 135);
+        MediumOrchid3, "MediumOrchid3", 133, (175, 95, 175);
+        MediumOrchid, "MediumOrchid", 134, (175, 95, 215);
+        MediumPurple2, "MediumPurple2", 135, (175, 95, 255);
+        DarkGoldenrod, "DarkGoldenrod", 136, (175, 135, 0);
+        LightSalmon3, "LightSalmon3", 137, (175, 135, 95);
+        RosyBrown, "RosyBrown", 138, (175, 135, 135);
+        Grey63, "Grey63", 139, (175, 135, 175);
+        MediumPurple2_140, "MediumPurple2", 140, (175, 135, 215);
+        MediumPurple1, "MediumPurple1", 141, (175, 135, 255);
+        Gold3, "Gold3", 142, (175, 175, 0);
+        DarkKhaki, "DarkKhaki", 143, (175, 175, 95);
+        NavajoWhite3, "NavajoWhite3", 144, (175, 175, 135);
+        Grey69, "Grey69", 145, (175, 175, 175);
+        LightSteelBlue3, "LightSteelBlue3", 146, (175, 175, 215);
+        LightSteelBlue, "LightSteelBlue", 147, (175, 175, 255);
+        Yellow3, "Yellow3", 148, (175, 215, 0);
+        DarkOliveGreen3_149, "DarkOliveGreen3", 149, (175, 215, 95);
+        DarkSeaGreen3_150, "DarkSeaGreen3", 150, (175, 215, 135);
+        DarkSeaGreen2, "DarkSeaGreen2", 151, (175, 215, 175);
+        LightCyan3, "LightCyan3", 152, (175, 215, 215);
+        LightSkyBlue1, "LightSkyBlue1", 153, (175, 215, 255);
+        GreenYellow, "GreenYellow", 154, (175, 255, 0);
+        DarkOliveGreen2, "DarkOliveGreen2", 155, (175, 255, 95);
+        PaleGreen1_156, "PaleGreen1", 156, (175, 255, 135);
+        DarkSeaGreen2_157, "DarkSeaGreen2", 157, (175, 255, 175);
+        DarkSeaGreen1, "DarkSeaGreen1", 158, (175, 255, 215);
+        PaleTurquoise1, "PaleTurquoise1", 159, (175, 255, 255);
+        Red3_160, "Red3", 160, (215, 0, 0);
+        DeepPink3, "DeepPink3", 161, (215, 0, 95);
+        DeepPink3_162, "DeepPink3", 162, (215, 0, 135);
+        Magenta3_163, "Magenta3", 163, (215, 0, 175);
+        Magenta3_164, "Magenta3", 164, (215, 0, 215);
+        Magenta2, "Magenta2", 165, (215, 0, 255);
+        DarkOrange3_166, "DarkOrange3", 166, (215, 95, 0);
+        IndianRed_167, "IndianRed", 167, (215, 95, 95);
+        HotPink3_168, "HotPink3", 168, (215, 95, 135);
+        HotPink2, "HotPink2", 169, (215, 95, 175);
+        Orchid, "Orchid", 170, (215, 95, 215);
+        MediumOrchid1, "MediumOrchid1", 171, (215, 95, 255);
+        Orange3, "Orange3", 172, (215, 135, 0);
+        LightSalmon3_173, "LightSalmon3", 173, (215, 135, 95);
+        LightPink3, "LightPink3", 174, (215, 135, 135);
+        Pink3, "Pink3", 175, (215, 135, 175);
+        Plum3, "Plum3", 176, (215, 135, 215);
+        Violet, "Violet", 177, (215, 135, 255);
+        Gold3_178, "Gold3", 178, (215, 175, 0);
+        LightGoldenrod3, "LightGoldenrod3", 179, (215, 175, 95);
+        Tan, "Tan", 180, (215, 175, 135);
+        MistyRose3, "MistyRose3", 181, (215, 175, 175);
+        Thistle3, "Thistle3", 182, (215, 175, 215);
+        Plum2, "Plum2", 183, (215, 175, 255);
+        Yellow3_184, "Yellow3", 184, (215, 215, 0);
+        Khaki3, "Khaki3", 185, (215, 215, 95);
+        LightGoldenrod2, "LightGoldenrod2", 186, (215, 215, 135);
+        LightYellow3, "LightYellow3", 187, (215, 215, 175);
+        Grey84, "Grey84", 188, (215, 215, 215);
+        LightSteelBlue1, "LightSteelBlue1", 189, (215, 215, 255);
+        Yellow2, "Yellow2", 190, (215, 255, 0);
+        DarkOliveGreen1, "DarkOliveGreen1", 191, (215, 255, 95);
+        DarkOliveGreen1_192, "DarkOliveGreen1", 192, (215, 255, 135);
+        DarkSeaGreen1_193, "DarkSeaGreen1", 193, (215, 255, 175);
+        Honeydew2, "Honeydew2", 194, (215, 255, 215);
+        LightCyan1, "LightCyan1", 195, (215, 255, 255);
+        Red1, "Red1", 196, (255, 0, 0);
+        DeepPink2, "DeepPink2", 197, (255, 0, 95);
+        DeepPink1, "DeepPink1", 198, (255, 0, 135);
+        DeepPink1_199, "DeepPink1", 199, (255, 0, 175);
+        Magenta2_200, "Magenta2", 200, (255, 0, 215);
+        Magenta1, "Magenta1", 201, (255, 0, 255);
+        OrangeRed1, "OrangeRed1", 202, (255, 95, 0);
+        IndianRed1, "IndianRed1", 203, (255, 95, 95);
+        IndianRed1_204, "IndianRed1", 204, (255, 95, 135);
+        HotPink, "HotPink", 205, (255, 95, 175);
+        HotPink_206, "HotPink", 206, (255, 95, 215);
+        MediumOrchid1_207, "MediumOrchid1", 207, (255, 95, 255);
+        DarkOrange, "DarkOrange", 208, (255, 135, 0);
+        Salmon1, "Salmon1", 209, (255, 135, 95);
+        LightCoral, "LightCoral", 210, (255, 135, 135);
+        PaleVioletRed1, "PaleVioletRed1", 211, (255, 135, 175);
+        Orchid2, "Orchid2", 212, (255, 135, 215);
+        Orchid1, "Orchid1", 213, (255, 135, 255);
+        Orange1, "Orange1", 214, (255, 175, 0);
+        SandyBrown, "SandyBrown", 215, (255, 175, 95);
+        LightSalmon1, "LightSalmon1", 216, (255, 175, 135);
+        LightPink1, "LightPink1", 217, (255, 175, 175);
+        Pink1, "Pink1", 218, (255, 175, 215);
+        Plum1, "Plum1", 219, (255, 175, 255);
+        Gold1, "Gold1", 220, (255, 215, 0);
+        LightGoldenrod2_221, "LightGoldenrod2", 221, (255, 215, 95);
+        LightGoldenrod2_222, "LightGoldenrod2", 222, (255, 215, 135);
+        NavajoWhite1, "NavajoWhite1", 223, (255, 215, 175);
+        MistyRose1, "MistyRose1", 224, (255, 215, 215);
+        Thistle1, "Thistle1", 225, (255, 215, 255);
+        Yellow1, "Yellow1", 226, (255, 255, 0);
+        LightGoldenrod1, "LightGoldenrod1", 227, (255, 255, 95);
+        Khaki1, "Khaki1", 228, (255, 255, 135);
+        Wheat1, "Wheat1", 229, (255, 255, 175);
+        Cornsilk1, "Cornsilk1", 230, (255, 255, 215);
+        Grey100, "Grey100", 231, (255, 255, 255);
+        Grey3, "Grey3", 232, (8, 8, 8);
+        Grey7, "Grey7", 233, (18, 18, 18);
+        Grey11, "Grey11", 234, (28, 28, 28);
+        Grey15, "Grey15", 235, (38, 38, 38);
+        Grey19, "Grey19", 236, (48, 48, 48);
+        Grey23, "Grey23", 237, (58, 58, 58);
+        Grey27, "Grey27", 238, (68, 68, 68);
+        Grey30, "Grey30", 239, (78, 78, 78);
+        Grey35, "Grey35", 240, (88, 88, 88);
+        Grey39, "Grey39", 241, (98, 98, 98);
+        Grey42, "Grey42", 242, (108, 108, 108);
+        Grey46, "Grey46", 243, (118, 118, 118);
+        Grey50, "Grey50", 244, (128, 128, 128);
+        Grey54, "Grey54", 245, (138, 138, 138);
+        Grey58, "Grey58", 246, (148, 148, 148);
+        Grey62, "Grey62", 247, (158, 158, 158);
+        Grey66, "Grey66", 248, (168, 168, 168);
+        Grey70, "Grey70", 249, (178, 178, 178);
+        Grey74, "Grey74", 250, (188, 188, 188);
+        Grey78, "Grey78", 251, (198, 198, 198);
+        Grey82, "Grey82", 252, (208, 208, 208);
+        Grey85, "Grey85", 253, (218, 218, 218);
+        Grey89, "Grey89", 254, (228, 228, 228);
+        Grey93, "Grey93", 255, (238, 238, 238);
+    }
+}
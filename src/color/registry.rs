@@ -0,0 +1,338 @@
+use std::{collections::BTreeMap, fmt::Display, path::Path, str::FromStr, sync::RwLock};
+
+use super::{
+    colormap::Colormap,
+    expression::FUNCTIONS,
+    libraries::{normalize_name, ColorLibrary, XtermColors},
+    Rgb,
+};
+
+///Wrappers used by the built in color sources, which can't be registered. The palette libraries
+///are reserved whether or not their feature is enabled.
+const RESERVED: [&str; 12] = [
+    "css",
+    "xterm",
+    "xterm256",
+    "rgb",
+    "palette",
+    "tailwind",
+    "material",
+    "catppuccin",
+    "nord",
+    "solarized",
+    "gruvbox",
+    "dracula",
+];
+
+static REGISTRY: RwLock<BTreeMap<String, CustomLibrary>> = RwLock::new(BTreeMap::new());
+
+///This enum defines errors that may be encountered when creating or registering a
+///[CustomLibrary]
+#[derive(Debug, Clone, PartialEq)]
+pub enum RegistryError {
+    ///The wrapper is empty or contains characters other than ASCII letters, digits and `_`
+    InvalidWrapper(String),
+    ///The wrapper is used by a built in color source (e.g. `css`), colormap or color expression
+    ///function
+    ReservedWrapper(String),
+    ///A color name is empty or contains parentheses
+    InvalidName(String),
+    ///A line of a library file could not be parsed. Contains the line number, starting at 1.
+    InvalidLine(usize),
+    ///A library file could not be read. Contains the error message.
+    Io(String),
+}
+
+impl Display for RegistryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidWrapper(wrapper) => write!(f, "Invalid library wrapper '{}'", wrapper),
+            Self::ReservedWrapper(wrapper) => {
+                write!(f, "Library wrapper '{}' is reserved", wrapper)
+            }
+            Self::InvalidName(name) => write!(f, "Invalid color name '{}'", name),
+            Self::InvalidLine(line) => write!(f, "Invalid color definition on line {}", line),
+            Self::Io(message) => write!(f, "Could not read color library: {}", message),
+        }
+    }
+}
+
+///A color in a [CustomLibrary]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CustomColor {
+    ///An RGB value. Can be parsed as an [Rgb].
+    Rgb([u8; 3]),
+    ///An ANSI256 index. Can be parsed as a [Fixed](super::Fixed) or, using the standard xterm
+    ///palette, an [Rgb].
+    Fixed(u8),
+}
+
+impl CustomColor {
+    ///Get the RGB value of the color, using the standard xterm palette for
+    ///[CustomColor::Fixed]
+    pub fn rgb(&self) -> [u8; 3] {
+        match self {
+            Self::Rgb(rgb) => *rgb,
+            Self::Fixed(code) => XtermColors::get_ansi256(*code).rgb(),
+        }
+    }
+}
+
+///A named set of colors referenced with a custom wrapper, e.g. `brand(primary)`. Libraries are
+///made available to parsing with [ColorRegistry::register].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomLibrary {
+    wrapper: String,
+    colors: Vec<(String, CustomColor)>,
+}
+
+impl CustomLibrary {
+    ///Create an empty library with a wrapper
+    pub fn new(wrapper: &str) -> Self {
+        Self {
+            wrapper: wrapper.to_string(),
+            colors: Vec::new(),
+        }
+    }
+
+    ///Add an RGB color to the library
+    pub fn rgb(mut self, name: &str, value: [u8; 3]) -> Self {
+        self.set(name, CustomColor::Rgb(value));
+        self
+    }
+
+    ///Add an ANSI256 color to the library
+    pub fn fixed(mut self, name: &str, code: u8) -> Self {
+        self.set(name, CustomColor::Fixed(code));
+        self
+    }
+
+    ///Add a color to the library, replacing any color with the same name
+    pub fn set(&mut self, name: &str, color: CustomColor) {
+        match self.colors.iter_mut().find(|(n, _)| n == name) {
+            Some(entry) => entry.1 = color,
+            None => self.colors.push((name.to_string(), color)),
+        }
+    }
+
+    ///Parse a library from lines of `name = color`, where a color is an ANSI256 index or anything
+    ///[Rgb] can be parsed from (e.g. `#ff5f00` or `css(orange)`). Blank lines and lines starting
+    ///with `#` are ignored.
+    pub fn parse(wrapper: &str, source: &str) -> Result<Self, RegistryError> {
+        let mut library = Self::new(wrapper);
+        for (i, line) in source.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (name, value) = line
+                .split_once('=')
+                .ok_or(RegistryError::InvalidLine(i + 1))?;
+            let value = value.trim();
+            let color = match value.parse::<u8>() {
+                Ok(code) => CustomColor::Fixed(code),
+                Err(_) => Rgb::from_str(value)
+                    .map(|rgb| CustomColor::Rgb(rgb.get_rgb()))
+                    .map_err(|_| RegistryError::InvalidLine(i + 1))?,
+            };
+            library.set(name.trim(), color);
+        }
+        Ok(library)
+    }
+
+    ///Read and [parse](CustomLibrary::parse) a library file
+    pub fn load<P: AsRef<Path>>(wrapper: &str, path: P) -> Result<Self, RegistryError> {
+        let source = std::fs::read_to_string(path).map_err(|e| RegistryError::Io(e.to_string()))?;
+        Self::parse(wrapper, &source)
+    }
+
+    ///Get the wrapper of the library
+    pub fn wrapper(&self) -> &str {
+        &self.wrapper
+    }
+
+    ///Wrap a color name with the wrapper of the library, e.g. `brand(primary)`
+    pub fn wrap_name(&self, name: &str) -> String {
+        format!("{}({})", self.wrapper, name)
+    }
+
+    ///Get a color by name. Names are matched like [ColorLibrary::get_name], ignoring case,
+    ///spaces, underscores and hyphens.
+    pub fn get(&self, name: &str) -> Option<CustomColor> {
+        let exact = self.colors.iter().find(|(n, _)| n == name);
+        exact
+            .or_else(|| {
+                let name = normalize_name(name);
+                self.colors.iter().find(|(n, _)| normalize_name(n) == name)
+            })
+            .map(|(_, color)| *color)
+    }
+
+    ///Iterate over the names and colors of the library, in the order they were added
+    pub fn iter(&self) -> impl Iterator<Item = (&str, CustomColor)> {
+        self.colors
+            .iter()
+            .map(|(name, color)| (name.as_str(), *color))
+    }
+
+    ///Get the number of colors in the library
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    ///Returns whether the library has no colors
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    fn validate(&self) -> Result<(), RegistryError> {
+        let wrapper = &self.wrapper;
+        if wrapper.is_empty()
+            || !wrapper
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            return Err(RegistryError::InvalidWrapper(wrapper.clone()));
+        }
+        if RESERVED.contains(&wrapper.as_str())
+            || FUNCTIONS.contains(&wrapper.as_str())
+            || Colormap::from_name(wrapper).is_some()
+        {
+            return Err(RegistryError::ReservedWrapper(wrapper.clone()));
+        }
+        match self
+            .colors
+            .iter()
+            .find(|(name, _)| name.trim().is_empty() || name.contains(['(', ')']))
+        {
+            Some((name, _)) => Err(RegistryError::InvalidName(name.clone())),
+            None => Ok(()),
+        }
+    }
+}
+
+///The global set of [CustomLibrary]s consulted when parsing [Rgb] and [Fixed](super::Fixed)
+///colors, from strings or with serde.
+///
+///A color parsed from a registered library keeps its name as its source, so it serializes back
+///to the name just like `css(...)`:
+///```rust
+///use std::str::FromStr;
+///use antsee::{color::registry::{ColorRegistry, CustomLibrary}, Rgb};
+///
+///ColorRegistry::register(CustomLibrary::new("brand").rgb("primary", [255, 95, 0])).unwrap();
+///let primary = Rgb::from_str("brand(primary)").unwrap();
+///assert_eq!(primary.get_rgb(), [255, 95, 0]);
+///assert_eq!(primary.to_string(), "brand(primary)");
+///```
+///[Rgb] accepts every color in a library, while [Fixed](super::Fixed) only accepts
+///[CustomColor::Fixed] colors.
+pub struct ColorRegistry;
+
+impl ColorRegistry {
+    ///Register a library, returning the library previously registered with the same wrapper
+    pub fn register(library: CustomLibrary) -> Result<Option<CustomLibrary>, RegistryError> {
+        library.validate()?;
+        let mut registry = REGISTRY.write().unwrap_or_else(|e| e.into_inner());
+        Ok(registry.insert(library.wrapper.clone(), library))
+    }
+
+    ///Remove a library, returning it if it was registered
+    pub fn unregister(wrapper: &str) -> Option<CustomLibrary> {
+        let mut registry = REGISTRY.write().unwrap_or_else(|e| e.into_inner());
+        registry.remove(wrapper)
+    }
+
+    ///Get a copy of a registered library
+    pub fn get(wrapper: &str) -> Option<CustomLibrary> {
+        let registry = REGISTRY.read().unwrap_or_else(|e| e.into_inner());
+        registry.get(wrapper).cloned()
+    }
+
+    ///Get the wrappers of every registered library, in alphabetical order
+    pub fn wrappers() -> Vec<String> {
+        let registry = REGISTRY.read().unwrap_or_else(|e| e.into_inner());
+        registry.keys().cloned().collect()
+    }
+
+    ///Look up a wrapped name such as `brand(primary)` in the registered libraries
+    pub fn lookup(s: &str) -> Option<CustomColor> {
+        let (wrapper, rest) = s.split_once('(')?;
+        let name = rest.strip_suffix(')')?;
+        let registry = REGISTRY.read().unwrap_or_else(|e| e.into_inner());
+        registry.get(wrapper)?.get(name)
+    }
+}
+
+#[cfg(test)]
+mod registry_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_library() {
+        let library = CustomLibrary::parse(
+            "parsed",
+            "# Brand colors\nprimary = #ff5f00\n\nsecondary = css(navy)\naccent = 208\n",
+        )
+        .unwrap();
+        assert_eq!(library.get("Primary"), Some(CustomColor::Rgb([255, 95, 0])));
+        assert_eq!(
+            library.get("secondary"),
+            Some(CustomColor::Rgb([0, 0, 128]))
+        );
+        assert_eq!(library.get("accent"), Some(CustomColor::Fixed(208)));
+        assert_eq!(library.len(), 3);
+        assert_eq!(
+            CustomLibrary::parse("parsed", "primary = #ff5f00\nsecondary"),
+            Err(RegistryError::InvalidLine(2))
+        );
+        assert_eq!(
+            CustomLibrary::parse("parsed", "primary = notacolor"),
+            Err(RegistryError::InvalidLine(1))
+        );
+    }
+
+    #[test]
+    fn test_load_library() {
+        let path = std::env::temp_dir().join("antsee_registry_test.colors");
+        std::fs::write(&path, "primary = #ff5f00\n").unwrap();
+        let library = CustomLibrary::load("loaded", &path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(library.get("primary"), Some(CustomColor::Rgb([255, 95, 0])));
+        assert!(matches!(
+            CustomLibrary::load("loaded", &path),
+            Err(RegistryError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_register() {
+        assert_eq!(
+            ColorRegistry::register(CustomLibrary::new("css")),
+            Err(RegistryError::ReservedWrapper("css".into()))
+        );
+        assert_eq!(
+            ColorRegistry::register(CustomLibrary::new("mix")),
+            Err(RegistryError::ReservedWrapper("mix".into()))
+        );
+        assert_eq!(
+            ColorRegistry::register(CustomLibrary::new("my brand")),
+            Err(RegistryError::InvalidWrapper("my brand".into()))
+        );
+        assert_eq!(
+            ColorRegistry::register(CustomLibrary::new("registered").rgb("a(b)", [0; 3])),
+            Err(RegistryError::InvalidName("a(b)".into()))
+        );
+
+        let library = CustomLibrary::new("registered").rgb("primary", [1, 2, 3]);
+        assert_eq!(ColorRegistry::register(library.clone()), Ok(None));
+        assert!(ColorRegistry::wrappers().contains(&"registered".to_string()));
+        assert_eq!(
+            ColorRegistry::lookup("registered(primary)"),
+            Some(CustomColor::Rgb([1, 2, 3]))
+        );
+        assert_eq!(ColorRegistry::lookup("registered(other)"), None);
+        assert_eq!(ColorRegistry::unregister("registered"), Some(library));
+        assert_eq!(ColorRegistry::lookup("registered(primary)"), None);
+    }
+}
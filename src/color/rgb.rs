@@ -1,26 +1,33 @@
-use std::{fmt::Display, rc::Rc, str::FromStr};
+use std::{convert::Infallible, fmt::Display, rc::Rc, str::FromStr};
 
 #[cfg(feature = "serde")]
 use serde::{de::Visitor, ser::SerializeSeq, Deserialize, Serialize};
 
 use super::{
-    libraries::{ColorLibrary, CssColors, XtermColors},
-    ColorFromStrError, ColorValue, Source,
+    colormap::parse_sample,
+    expression::{self, EvalError},
+    libraries::{wrapped_rgb, ColorLibrary, CssColors, Xterm256Colors, XtermColors},
+    parse_reference,
+    registry::ColorRegistry,
+    space::{Oklab, Oklch},
+    ColorFromStrError, ColorSource, ColorValue, DistanceMetric, Fixed, Source,
 };
 
-/** The RGB colour type, containing a simple u8 array to represent the color value */
+/** The RGB colour type, containing a simple u8 array to represent the color value, and whether
+ * the value is still unresolved (see [Rgb::is_resolved]) */
 #[derive(Debug, Clone, Default, PartialEq)]
-pub struct Rgb([u8; 3], Source<Rc<str>>);
+pub struct Rgb([u8; 3], Source<Rc<str>>, bool);
 
 impl Rgb {
     ///Construct a new Rgb instance from an array of u8s
     pub fn new() -> Rgb {
-        Rgb([0; 3], Source::Inactive(Rc::from("")))
+        Rgb([0; 3], Source::Inactive(Rc::from("")), false)
     }
 
     ///Set the value of the colour with RGB
     pub fn rgb(mut self, val: [u8; 3]) -> Self {
         self.0 = val;
+        self.2 = false;
         self
     }
 
@@ -35,20 +42,158 @@ impl Rgb {
         let new = Self::from_hex(hex)?;
         self.0 = new.0;
         self.1 = Source::Active(Rc::from(hex));
+        self.2 = false;
         Ok(())
     }
 
     ///Set the RGB color with a value from [CssColors] or [XtermColors]
     pub fn color<C: ColorLibrary>(mut self, color: C) -> Self {
-        self.0 = color.rgb();
-        self.1 = Source::Active(Rc::from(color.color_name()));
+        self.set_color(color);
         self
     }
 
     ///Set the RGB color with a value from [CssColors] or [XtermColors]
     pub fn set_color<C: ColorLibrary>(&mut self, color: C) {
         self.0 = color.rgb();
-        self.1 = Source::Active(Rc::from(color.color_name()))
+        self.1 = Source::Active(Rc::from(C::wrap_name(color.color_name())));
+        self.2 = false;
+    }
+
+    ///Get the RGB value of the color. The value of an unresolved color is a placeholder, so
+    ///[Rgb::is_resolved] should be checked first, or [Color::to_rgb](super::Color::to_rgb) used,
+    ///which returns [None] for it.
+    pub fn get_rgb(&self) -> [u8; 3] {
+        self.0
+    }
+
+    ///Returns whether the color has a value. Palette references (`$name` or `palette(name)`) and
+    ///expressions using them are unresolved until they are resolved against a
+    ///[Palette](crate::theme::Palette), which happens when a [Theme](crate::Theme) is
+    ///deserialized.
+    pub fn is_resolved(&self) -> bool {
+        !self.2
+    }
+
+    ///Format the color as a lowercase hex string (e.g. `#ff8700`)
+    pub fn to_hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0[0], self.0[1], self.0[2])
+    }
+
+    ///Get the wrapped name of every [CssColors], [XtermColors] and [Xterm256Colors] color with
+    ///exactly this value, e.g. `["css(red)", "xterm(Red)", "xterm(BrightRed)", "xterm256(Red)"]`
+    pub fn exact_names(&self) -> Vec<String> {
+        let css = CssColors::exact_matches(self)
+            .into_iter()
+            .map(|color| CssColors::wrap_name(color.color_name()));
+        let xterm = XtermColors::exact_matches(self)
+            .into_iter()
+            .map(|color| XtermColors::wrap_name(color.color_name()));
+        let xterm256 = Xterm256Colors::exact_matches(self)
+            .into_iter()
+            .map(|color| Xterm256Colors::wrap_name(color.color_name()));
+        css.chain(xterm).chain(xterm256).collect()
+    }
+
+    ///Get the closest [Fixed] color to this color from the 6x6x6 color cube and grayscale ramp
+    ///(indices 16-255), using the default [DistanceMetric]. The first 16 indices are skipped as
+    ///terminals commonly redefine them.
+    pub fn to_fixed(&self) -> Fixed {
+        self.to_fixed_with(DistanceMetric::default())
+    }
+
+    ///Get the closest [Fixed] color to this color from indices 16-255 with a [DistanceMetric]
+    pub fn to_fixed_with(&self, metric: DistanceMetric) -> Fixed {
+        let candidates = (16..=255).map(|code| (code, XtermColors::get_ansi256(code).rgb()));
+        let (code, _) = metric.nearest(self.0, candidates).unwrap_or((16, 0.0));
+        Fixed::new().code(code)
+    }
+
+    ///Create a color from a value computed from this color. The source becomes inactive, as the
+    ///value no longer matches it.
+    pub(crate) fn derived(&self, value: [u8; 3]) -> Self {
+        Self(value, self.1.clone().inactive(), self.2)
+    }
+
+    fn adjust_oklch(&self, adjust: impl FnOnce(&mut Oklch)) -> Self {
+        let mut oklch = Oklch::from_rgb(self.0);
+        adjust(&mut oklch);
+        self.derived(oklch.to_rgb())
+    }
+
+    ///Increase the Oklab lightness of the color by `amount` (0-1), keeping its hue
+    pub fn lighten(&self, amount: f64) -> Self {
+        self.adjust_oklch(|oklch| oklch.l += amount)
+    }
+
+    ///Decrease the Oklab lightness of the color by `amount` (0-1), keeping its hue
+    pub fn darken(&self, amount: f64) -> Self {
+        self.lighten(-amount)
+    }
+
+    ///Increase the chroma of the color by a fraction of its current chroma (`0.2` is 20% more
+    ///saturated). Colors are mapped back into the sRGB gamut at the same lightness and hue.
+    pub fn saturate(&self, amount: f64) -> Self {
+        self.adjust_oklch(|oklch| oklch.c *= (1.0 + amount).max(0.0))
+    }
+
+    ///Decrease the chroma of the color by a fraction of its current chroma (`1.0` is fully gray)
+    pub fn desaturate(&self, amount: f64) -> Self {
+        self.saturate(-amount)
+    }
+
+    ///Rotate the Oklch hue of the color by a number of degrees
+    pub fn rotate_hue(&self, degrees: f64) -> Self {
+        self.adjust_oklch(|oklch| oklch.h = (oklch.h + degrees).rem_euclid(360.0))
+    }
+
+    ///Mix the color with another in Oklab. `amount` is the share of `other`, so `0.0` returns this
+    ///color and `1.0` returns `other`.
+    pub fn mix(&self, other: &Rgb, amount: f64) -> Self {
+        let (a, b) = (Oklab::from_rgb(self.0), Oklab::from_rgb(other.0));
+        let mut mixed = self.derived(
+            Oklab {
+                l: a.l + (b.l - a.l) * amount,
+                a: a.a + (b.a - a.a) * amount,
+                b: a.b + (b.b - a.b) * amount,
+            }
+            .to_rgb(),
+        );
+        mixed.2 |= other.2;
+        mixed
+    }
+
+    ///Invert each channel of the color
+    pub fn invert(&self) -> Self {
+        self.derived(self.0.map(|c| 255 - c))
+    }
+
+    ///Remove the chroma of the color, keeping its Oklab lightness
+    pub fn grayscale(&self) -> Self {
+        self.adjust_oklch(|oklch| oklch.c = 0.0)
+    }
+
+    ///Composite the color over a background with an opacity (0-1), as a terminal would draw a
+    ///translucent color. Blending happens in sRGB to match how terminals and browsers composite.
+    pub fn with_alpha_over(&self, background: &Rgb, alpha: f64) -> Self {
+        let alpha = alpha.clamp(0.0, 1.0);
+        let mut composited = self.derived([0, 1, 2].map(|i| {
+            (self.0[i] as f64 * alpha + background.0[i] as f64 * (1.0 - alpha)).round() as u8
+        }));
+        composited.2 |= background.2;
+        composited
+    }
+
+    ///Get the name of the palette entry this color references, if its active source is a palette
+    ///reference (`$name` or `palette(name)`)
+    pub fn palette_reference(&self) -> Option<&str> {
+        self.active_source().and_then(parse_reference)
+    }
+
+    pub(crate) fn active_source(&self) -> Option<&str> {
+        match &self.1 {
+            Source::Active(s) => Some(s),
+            Source::Inactive(_) => None,
+        }
     }
 
     fn from_hex(hex: &str) -> Result<Self, ColorFromStrError> {
@@ -64,7 +209,7 @@ impl Rgb {
         let g = u8::from_str_radix(&hex[2..4], 16).map_err(|_| ColorFromStrError::InvalidValue)?;
         let b = u8::from_str_radix(&hex[4..6], 16).map_err(|_| ColorFromStrError::InvalidValue)?;
 
-        Ok(Self([r, g, b], Source::Active(Rc::from(fullhex))))
+        Ok(Self([r, g, b], Source::Active(Rc::from(fullhex)), false))
     }
 }
 
@@ -73,31 +218,63 @@ where
     C: ColorLibrary,
 {
     fn from(value: C) -> Self {
-        Rgb(value.rgb(), Source::Active(Rc::from(value.color_name())))
+        Rgb(
+            value.rgb(),
+            Source::Active(Rc::from(C::wrap_name(value.color_name()))),
+            false,
+        )
     }
 }
 
 impl ColorValue for Rgb {}
 
+impl ColorSource for Rgb {
+    type ExternalSource = String;
+    fn set_external_source(&mut self, value: Self::ExternalSource) {
+        self.1 = Source::Active(Rc::from(value))
+    }
+    fn source_external(&mut self) {
+        self.1 = self.1.clone().active()
+    }
+    fn source_internal(&mut self) {
+        self.1 = self.1.clone().inactive()
+    }
+}
+
 impl FromStr for Rgb {
     type Err = ColorFromStrError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s.starts_with('#') {
             return Self::from_hex(s);
         }
-        if s.starts_with("css(") && s.ends_with(')') {
-            let color_name = CssColors::unwrap_name(s);
-            let css_color = CssColors::get_name(color_name);
-            if let Some(color) = css_color {
-                return Ok(Self::from(color));
-            }
+        if parse_reference(s).is_some() {
+            //The color has no value until the reference is resolved against a palette
+            return Ok(Self([0; 3], Source::Active(Rc::from(s)), true));
         }
-        if s.starts_with("xterm(") && s.ends_with(")") {
-            let color_name = XtermColors::unwrap_name(s);
-            let xterm_color = XtermColors::get_name(color_name);
-            if let Some(color) = xterm_color {
-                return Ok(Self::from(color));
-            }
+        if expression::is_expression(s) {
+            //Expressions are validated with placeholder values for any references, and have no
+            //value until the references are resolved against a palette
+            let mut unresolved = false;
+            let value = expression::evaluate(s, &mut |_| {
+                unresolved = true;
+                Ok::<_, Infallible>([0; 3])
+            })
+            .map_err(|e| match e {
+                EvalError::Invalid(e) => e,
+                EvalError::Lookup(e) => match e {},
+            })?;
+            let value = if unresolved { [0; 3] } else { value };
+            return Ok(Self(value, Source::Active(Rc::from(s)), unresolved));
+        }
+        if let Some(value) = wrapped_rgb(s) {
+            //Keep the name as it was written, which may differ from the library name
+            return Ok(Self(value, Source::Active(Rc::from(s)), false));
+        }
+        if let Some(value) = parse_sample(s) {
+            return Ok(Self(value?, Source::Active(Rc::from(s)), false));
+        }
+        if let Some(color) = ColorRegistry::lookup(s) {
+            return Ok(Self(color.rgb(), Source::Active(Rc::from(s)), false));
         }
         Err(ColorFromStrError::InvalidString)
     }
@@ -126,9 +303,21 @@ impl Serialize for Rgb {
     }
 }
 
+///Returns whether a source is a name wrapped in the wrapper of a built in library (e.g.
+///`css(red)`), a colormap sample, or a name from a library in the [ColorRegistry]
+fn is_library_name(s: &str) -> bool {
+    wrapped_rgb(s).is_some()
+        || parse_sample(s).is_some_and(|value| value.is_ok())
+        || ColorRegistry::lookup(s).is_some()
+}
+
 impl Display for Rgb {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if let Source::Active(c) = self.clone().1 {
+            //Unresolved colors have no value, so they are written as their reference
+            if is_library_name(&c) || self.2 {
+                return write!(f, "{}", c);
+            }
             if CssColors::get_name(&c).is_some() {
                 write!(f, "css({})", c)?;
                 return Ok(());
@@ -168,7 +357,7 @@ impl<'de> Visitor<'de> for RgbVisitor {
     where
         A: serde::de::SeqAccess<'de>,
     {
-        let mut rgb = Rgb([0, 0, 0], Source::Inactive(Rc::from("")));
+        let mut rgb = Rgb([0, 0, 0], Source::Inactive(Rc::from("")), false);
         let r = seq.next_element()?;
         if let Some(red) = r {
             rgb.0[0] = red;
@@ -200,6 +389,7 @@ mod rgb_tests {
     use serde_test::{assert_tokens, Token};
 
     use super::*;
+    use crate::color::{colormap::Colormap, registry::CustomLibrary, Color};
 
     #[test]
     fn test_rgb_from_hex() {
@@ -207,7 +397,7 @@ mod rgb_tests {
         assert!(rgb.is_ok());
         assert_eq!(
             rgb.unwrap(),
-            Rgb([50, 69, 130], Source::Active(Rc::from("#324582")))
+            Rgb([50, 69, 130], Source::Active(Rc::from("#324582")), false)
         );
     }
 
@@ -219,11 +409,154 @@ mod rgb_tests {
             rgb.unwrap(),
             Rgb(
                 CssColors::Red.rgb(),
-                Source::Active(Rc::from(CssColors::Red.color_name()))
+                Source::Active(Rc::from(CssColors::wrap_name(CssColors::Red.color_name()))),
+                false
             )
         );
     }
 
+    #[test]
+    fn test_rgb_keeps_name_spelling() {
+        let rgb = Rgb::from_str("css(Dark Grey)").unwrap();
+        assert_eq!(rgb.get_rgb(), CssColors::DarkGray.rgb());
+        assert_tokens(&rgb, &[Token::Str("css(Dark Grey)")]);
+        assert_eq!(rgb.to_string(), "css(Dark Grey)");
+        let rgb = Rgb::from_str("xterm(Red)").unwrap();
+        assert_eq!(rgb.get_rgb(), XtermColors::Red.rgb());
+        assert_tokens(&rgb, &[Token::Str("xterm(Red)")]);
+        assert_tokens(&Rgb::from(CssColors::Red), &[Token::Str("css(red)")]);
+        let rgb = Rgb::from_str("xterm256(DeepSkyBlue4)").unwrap();
+        assert_eq!(rgb.get_rgb(), XtermColors::get_ansi256(23).rgb());
+        assert_tokens(&rgb, &[Token::Str("xterm256(DeepSkyBlue4)")]);
+    }
+
+    #[cfg(feature = "palettes")]
+    #[test]
+    fn test_rgb_palette_libraries() {
+        let rgb = Rgb::from_str("tailwind(blue-500)").unwrap();
+        assert_eq!(rgb.get_rgb(), [59, 130, 246]);
+        assert_tokens(&rgb, &[Token::Str("tailwind(blue-500)")]);
+        assert_eq!(rgb.to_string(), "tailwind(blue-500)");
+        let rgb = Rgb::from(crate::color::libraries::CatppuccinColors::MochaMauve);
+        assert_tokens(&rgb, &[Token::Str("catppuccin(mocha.mauve)")]);
+    }
+
+    #[test]
+    fn test_rgb_colormap() {
+        let rgb = Rgb::from_str("viridis(0.35)").unwrap();
+        assert_eq!(rgb.get_rgb(), Colormap::Viridis.sample(0.35).get_rgb());
+        assert_tokens(&rgb, &[Token::Str("viridis(0.35)")]);
+        assert_tokens(&Colormap::Magma.sample(0.8), &[Token::Str("magma(0.8)")]);
+        assert_eq!(
+            Rgb::from_str("viridis(2)"),
+            Err(ColorFromStrError::InvalidValue)
+        );
+    }
+
+    #[test]
+    fn test_rgb_registry() {
+        assert_eq!(
+            Rgb::from_str("rgbbrand(primary)"),
+            Err(ColorFromStrError::InvalidString)
+        );
+        let library = CustomLibrary::new("rgbbrand")
+            .rgb("primary", [255, 95, 0])
+            .fixed("accent", 208);
+        ColorRegistry::register(library).unwrap();
+        let rgb = Rgb::from_str("rgbbrand(primary)").unwrap();
+        assert_eq!(rgb.get_rgb(), [255, 95, 0]);
+        assert_tokens(&rgb, &[Token::Str("rgbbrand(primary)")]);
+        assert_eq!(rgb.to_string(), "rgbbrand(primary)");
+        let rgb = Rgb::from_str("rgbbrand(accent)").unwrap();
+        assert_eq!(rgb.get_rgb(), XtermColors::get_ansi256(208).rgb());
+        let mixed = Rgb::from_str("mix(rgbbrand(primary), #000000, 0)").unwrap();
+        assert_eq!(mixed.get_rgb(), [255, 95, 0]);
+    }
+
+    #[test]
+    fn test_rgb_palette_reference() {
+        let rgb = Rgb::from_str("palette(accent)").unwrap();
+        assert_eq!(rgb.palette_reference(), Some("accent"));
+        assert_eq!(
+            Rgb::from_str("$accent").unwrap().palette_reference(),
+            Some("accent")
+        );
+        assert!(Rgb::from_str("$").is_err());
+        assert_eq!(Rgb::from_str("#324582").unwrap().palette_reference(), None);
+
+        assert!(!rgb.is_resolved());
+        assert!(!rgb.lighten(0.1).is_resolved());
+        assert!(!Rgb::new().mix(&rgb, 0.5).is_resolved());
+        assert_eq!(rgb.to_string(), "palette(accent)");
+        assert_eq!(Color::from(rgb.clone()).to_rgb(), None);
+        assert!(rgb.rgb([1, 2, 3]).is_resolved());
+    }
+
+    #[test]
+    fn test_rgb_manipulation() {
+        let tomato = Rgb::from(CssColors::Tomato);
+        let lightness = |rgb: &Rgb| Oklch::from_rgb(rgb.get_rgb()).l;
+        assert!(lightness(&tomato.lighten(0.1)) > lightness(&tomato));
+        assert!(lightness(&tomato.darken(0.1)) < lightness(&tomato));
+        let mut lighter = tomato.lighten(0.1);
+        assert_eq!(lighter.active_source(), None);
+        lighter.source_external();
+        assert_eq!(lighter.active_source(), Some("css(tomato)"));
+        assert_eq!(tomato.rotate_hue(360.0).get_rgb(), tomato.get_rgb());
+        assert_eq!(
+            tomato.desaturate(1.0).get_rgb(),
+            tomato.grayscale().get_rgb()
+        );
+        let gray = tomato.grayscale().get_rgb();
+        assert!(gray[0] == gray[1] && gray[1] == gray[2]);
+        assert_eq!(tomato.invert().get_rgb(), [0, 156, 184]);
+
+        let (black, white) = (Rgb::new(), Rgb::new().rgb([255; 3]));
+        assert_eq!(black.mix(&white, 0.0).get_rgb(), [0; 3]);
+        assert_eq!(black.mix(&white, 1.0).get_rgb(), [255; 3]);
+        assert_eq!(white.with_alpha_over(&black, 0.5).get_rgb(), [128; 3]);
+    }
+
+    #[test]
+    fn test_nearest_and_exact_names() {
+        let (tomato, distance) = CssColors::nearest(&Rgb::new().rgb([250, 100, 70])).unwrap();
+        assert_eq!(tomato.color_name(), "tomato");
+        assert!(distance > 0.0 && distance < 3.0);
+        let (seafoam, distance) = XtermColors::nearest(&Rgb::from(XtermColors::Seafoam)).unwrap();
+        assert_eq!((seafoam.color_name(), distance), ("Seafoam", 0.0));
+        let (black, _) = XtermColors::nearest_with(&Rgb::new(), DistanceMetric::Oklab).unwrap();
+        assert_eq!(black.ansi256(), 0);
+
+        let names = Rgb::new().rgb([255, 0, 0]).exact_names();
+        assert_eq!(
+            names,
+            [
+                "css(red)",
+                "xterm(Red)",
+                "xterm(BrightRed)",
+                "xterm256(Red)",
+                "xterm256(Red1)"
+            ]
+        );
+        assert!(Rgb::new().rgb([1, 2, 3]).exact_names().is_empty());
+    }
+
+    #[test]
+    fn test_rgb_expression() {
+        let rgb = Rgb::from_str("rotate_hue(xterm(Seafoam), 180)").unwrap();
+        assert_ne!(rgb.get_rgb(), [0; 3]);
+        assert_tokens(&rgb, &[Token::Str("rotate_hue(xterm(Seafoam), 180)")]);
+        assert!(rgb.is_resolved());
+        let unresolved = Rgb::from_str("darken($accent, 10%)").unwrap();
+        assert!(!unresolved.is_resolved());
+        assert_eq!(unresolved.to_string(), "darken($accent, 10%)");
+        assert_eq!(Color::from(unresolved).to_rgb(), None);
+        assert_eq!(
+            Rgb::from_str("mix(css(navy), $accent, lots)"),
+            Err(ColorFromStrError::InvalidValue)
+        );
+    }
+
     #[test]
     fn test_serialize_array() {
         let rgb = Rgb::new().rgb([32, 45, 0]);
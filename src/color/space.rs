@@ -0,0 +1,183 @@
+///Convert an 8 bit sRGB channel to linear light (0-1)
+pub(crate) fn to_linear(channel: u8) -> f64 {
+    let c = channel as f64 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+///Convert a linear light value (0-1) to an 8 bit sRGB channel, clamping values out of range
+pub(crate) fn from_linear(value: f64) -> u8 {
+    let c = if value <= 0.0031308 {
+        12.92 * value
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    };
+    (c.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+///A color in the Oklab color space
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Oklab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+///A color in the Oklch color space (Oklab in polar coordinates, hue in degrees)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Oklch {
+    pub l: f64,
+    pub c: f64,
+    pub h: f64,
+}
+
+impl Oklab {
+    pub fn from_rgb(rgb: [u8; 3]) -> Self {
+        Self::from_linear(rgb.map(to_linear))
+    }
+
+    pub fn from_linear([r, g, b]: [f64; 3]) -> Self {
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+        Self {
+            l: 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            a: 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            b: 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+        }
+    }
+
+    ///Convert to linear sRGB. The result may be outside of the 0-1 range.
+    pub fn to_linear(self) -> [f64; 3] {
+        let l = (self.l + 0.3963377774 * self.a + 0.2158037573 * self.b).powi(3);
+        let m = (self.l - 0.1055613458 * self.a - 0.0638541728 * self.b).powi(3);
+        let s = (self.l - 0.0894841775 * self.a - 1.2914855480 * self.b).powi(3);
+        [
+            4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+            -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+            -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+        ]
+    }
+
+    ///Convert to sRGB, reducing chroma until the color fits in the sRGB gamut
+    pub fn to_rgb(self) -> [u8; 3] {
+        Oklch::from(self).to_rgb()
+    }
+}
+
+///A color in the CIELAB color space, with a D65 white point
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Lab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+impl Lab {
+    pub fn from_rgb(rgb: [u8; 3]) -> Self {
+        let [r, g, b] = rgb.map(to_linear);
+        let x = 0.4124564 * r + 0.3575761 * g + 0.1804375 * b;
+        let y = 0.2126729 * r + 0.7151522 * g + 0.0721750 * b;
+        let z = 0.0193339 * r + 0.1191920 * g + 0.9503041 * b;
+        let f = |t: f64| {
+            const DELTA: f64 = 6.0 / 29.0;
+            if t > DELTA.powi(3) {
+                t.cbrt()
+            } else {
+                t / (3.0 * DELTA * DELTA) + 4.0 / 29.0
+            }
+        };
+        let (fx, fy, fz) = (f(x / 0.95047), f(y), f(z / 1.08883));
+        Self {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+}
+
+fn in_gamut(linear: [f64; 3]) -> bool {
+    linear.iter().all(|c| (-1e-4..=1.0 + 1e-4).contains(c))
+}
+
+impl Oklch {
+    pub fn from_rgb(rgb: [u8; 3]) -> Self {
+        Oklab::from_rgb(rgb).into()
+    }
+
+    ///Convert to sRGB, keeping lightness and hue and reducing chroma until the color fits in the
+    ///sRGB gamut
+    pub fn to_rgb(self) -> [u8; 3] {
+        let l = self.l.clamp(0.0, 1.0);
+        let mut color = Oklch { l, ..self };
+        if !in_gamut(Oklab::from(color).to_linear()) {
+            let (mut low, mut high) = (0.0, self.c);
+            for _ in 0..24 {
+                color.c = (low + high) / 2.0;
+                if in_gamut(Oklab::from(color).to_linear()) {
+                    low = color.c;
+                } else {
+                    high = color.c;
+                }
+            }
+            color.c = low;
+        }
+        Oklab::from(color).to_linear().map(from_linear)
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(value: Oklab) -> Self {
+        Self {
+            l: value.l,
+            c: value.a.hypot(value.b),
+            h: value.b.atan2(value.a).to_degrees().rem_euclid(360.0),
+        }
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(value: Oklch) -> Self {
+        let h = value.h.to_radians();
+        Self {
+            l: value.l,
+            a: value.c * h.cos(),
+            b: value.c * h.sin(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod space_tests {
+    use super::*;
+
+    #[test]
+    fn test_oklab_round_trip() {
+        for rgb in [
+            [0, 0, 0],
+            [255, 255, 255],
+            [255, 0, 0],
+            [18, 52, 86],
+            [0, 255, 127],
+        ] {
+            assert_eq!(Oklab::from_rgb(rgb).to_rgb(), rgb);
+        }
+        let white = Oklab::from_rgb([255, 255, 255]);
+        assert!((white.l - 1.0).abs() < 1e-3 && white.a.abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_gamut_mapping_keeps_lightness() {
+        let color = Oklch {
+            l: 0.7,
+            c: 0.5,
+            h: 150.0,
+        };
+        let mapped = Oklch::from_rgb(color.to_rgb());
+        assert!((mapped.l - 0.7).abs() < 0.01);
+        assert!(mapped.c < 0.5);
+    }
+}
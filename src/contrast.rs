@@ -0,0 +1,354 @@
+/*!
+Contrast between text and background colors.
+
+Two measures are provided:
+- [wcag_contrast] is the WCAG 2.x contrast ratio, from 1 to 21. WCAG AA asks for at least 4.5 for
+  body text and 3 for large text.
+- [apca_contrast] is the APCA lightness contrast (Lc), roughly -108 to 106. It is positive for
+  dark text on a light background and negative for light text on a dark background. An absolute
+  value of 75 is recommended for body text and 60 for larger text.
+
+[Ansi] and [Fixed] colors, as well as unset colors, depend on the terminal, so they are resolved
+to RGB with an [AnsiPalette]:
+```rust
+use antsee::{contrast::AnsiPalette, Ansi, Style};
+
+let style = Style::default().fg(Ansi::Black).bg(Ansi::White);
+let contrast = style.contrast_with(&AnsiPalette::default());
+assert!(contrast.wcag > 10.0);
+```
+*/
+
+use crate::{
+    color::space::Oklch,
+    color::{libraries::ColorLibrary, Ansi, Color, ColorSource, Rgb},
+    style::Style,
+    XtermColors,
+};
+
+///The RGB values a terminal uses for the 16 [Ansi] colors and its default foreground and
+///background
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnsiPalette {
+    ///Colors 0-15, in palette order (see [Ansi::index])
+    pub colors: [[u8; 3]; 16],
+    ///The color of text with the default foreground
+    pub foreground: [u8; 3],
+    ///The color of the default background
+    pub background: [u8; 3],
+}
+
+impl Default for AnsiPalette {
+    ///The xterm palette, with light gray text on a black background
+    fn default() -> Self {
+        let mut colors = [[0; 3]; 16];
+        for (i, color) in colors.iter_mut().enumerate() {
+            *color = XtermColors::get_ansi256(i as u8).rgb();
+        }
+        Self {
+            colors,
+            foreground: colors[7],
+            background: colors[0],
+        }
+    }
+}
+
+impl AnsiPalette {
+    ///Get the RGB value of a color. Colors 0-15 are taken from the palette, and the rest of the
+    ///[Fixed](crate::Fixed) colors from the standard xterm palette. [Ansi::Default] and unresolved
+    ///colors resolve to `default`.
+    pub fn rgb(&self, color: &Color, default: [u8; 3]) -> Rgb {
+        let value = match color {
+            Color::Ansi(ansi) => ansi.index().map_or(default, |i| self.colors[i as usize]),
+            Color::Fixed(fixed) if fixed.0 < 16 => self.colors[fixed.0 as usize],
+            Color::Fixed(fixed) => fixed.to_rgb().get_rgb(),
+            Color::Rgb(rgb) if !rgb.is_resolved() => default,
+            Color::Rgb(rgb) => rgb.get_rgb(),
+        };
+        Rgb::new().rgb(value)
+    }
+
+    ///Get the RGB value of a foreground color. Unset and default colors resolve to the default
+    ///foreground.
+    pub fn foreground(&self, color: Option<&Color>) -> Rgb {
+        self.rgb(color.unwrap_or(&Ansi::Default.into()), self.foreground)
+    }
+
+    ///Get the RGB value of a background color. Unset and default colors resolve to the default
+    ///background.
+    pub fn background(&self, color: Option<&Color>) -> Rgb {
+        self.rgb(color.unwrap_or(&Ansi::Default.into()), self.background)
+    }
+}
+
+///The contrast between the foreground and background of a [Style]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Contrast {
+    ///WCAG 2.x contrast ratio (1-21)
+    pub wcag: f64,
+    ///APCA lightness contrast (Lc) of the foreground on the background
+    pub apca: f64,
+}
+
+impl Contrast {
+    ///Measure the contrast of text on a background
+    pub fn new(text: &Rgb, background: &Rgb) -> Self {
+        Self {
+            wcag: wcag_contrast(text, background),
+            apca: apca_contrast(text, background),
+        }
+    }
+}
+
+///A minimum contrast for text on its background
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ContrastTarget {
+    ///A minimum WCAG 2.x contrast ratio, e.g. 4.5 for WCAG AA
+    Wcag(f64),
+    ///A minimum absolute APCA lightness contrast (Lc), e.g. 75 for body text
+    Apca(f64),
+}
+
+impl ContrastTarget {
+    ///Returns whether a contrast meets the target
+    pub fn is_met(&self, contrast: &Contrast) -> bool {
+        match self {
+            Self::Wcag(ratio) => contrast.wcag >= *ratio,
+            Self::Apca(lc) => contrast.apca.abs() >= *lc,
+        }
+    }
+}
+
+///Get the WCAG 2.x relative luminance of a color (0-1)
+pub fn relative_luminance(color: &Rgb) -> f64 {
+    let [r, g, b] = color.get_rgb().map(crate::color::space::to_linear);
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+///Get the WCAG 2.x contrast ratio between two colors (1-21). The order of the colors does not
+///matter.
+pub fn wcag_contrast(a: &Rgb, b: &Rgb) -> f64 {
+    let (a, b) = (relative_luminance(a), relative_luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+///Get the screen luminance APCA uses, with the soft clamp for near black colors
+fn apca_luminance(color: &Rgb) -> f64 {
+    let [r, g, b] = color.get_rgb().map(|c| (c as f64 / 255.0).powf(2.4));
+    let y = 0.2126729 * r + 0.7151522 * g + 0.0721750 * b;
+    if y < 0.022 {
+        y + (0.022 - y).powf(1.414)
+    } else {
+        y
+    }
+}
+
+///Get the APCA lightness contrast (Lc) of text on a background, using the APCA-W3 0.0.98G
+///constants. Positive values are dark text on a light background, negative values are light text on
+///a dark background.
+pub fn apca_contrast(text: &Rgb, background: &Rgb) -> f64 {
+    let (text, background) = (apca_luminance(text), apca_luminance(background));
+    if (background - text).abs() < 0.0005 {
+        return 0.0;
+    }
+    let contrast = if background > text {
+        let sapc = (background.powf(0.56) - text.powf(0.57)) * 1.14;
+        if sapc < 0.1 {
+            0.0
+        } else {
+            sapc - 0.027
+        }
+    } else {
+        let sapc = (background.powf(0.65) - text.powf(0.62)) * 1.14;
+        if sapc > -0.1 {
+            0.0
+        } else {
+            sapc + 0.027
+        }
+    };
+    contrast * 100.0
+}
+
+///Pick the candidate with the highest WCAG contrast against a background. Returns [None] if there
+///are no candidates.
+pub fn readable_foreground<'a>(background: &Rgb, candidates: &'a [Rgb]) -> Option<&'a Rgb> {
+    candidates
+        .iter()
+        .max_by(|a, b| wcag_contrast(a, background).total_cmp(&wcag_contrast(b, background)))
+}
+
+///Pick black or white, whichever is more readable on a background
+pub fn black_or_white(background: &Rgb) -> Rgb {
+    let candidates = [Rgb::new().rgb([0; 3]), Rgb::new().rgb([255; 3])];
+    readable_foreground(background, &candidates)
+        .cloned()
+        .unwrap_or_default()
+}
+
+impl Style {
+    ///Get the contrast of the foreground on the background in the default xterm palette. See
+    ///[Style::contrast_with].
+    pub fn contrast(&self) -> Contrast {
+        self.contrast_with(&AnsiPalette::default())
+    }
+
+    ///Get the contrast of the foreground on the background, resolving terminal colors with a
+    ///palette. The colors are swapped if the style is reversed.
+    pub fn contrast_with(&self, palette: &AnsiPalette) -> Contrast {
+        let (mut fg, mut bg) = (
+            palette.foreground(self.fg.as_ref()),
+            palette.background(self.bg.as_ref()),
+        );
+        if self.attributes.reverse.0 {
+            std::mem::swap(&mut fg, &mut bg);
+        }
+        Contrast::new(&fg, &bg)
+    }
+
+    ///Adjust the lightness of the foreground until it meets a contrast target against the
+    ///background in the default xterm palette. See [Style::ensure_contrast_with].
+    pub fn ensure_contrast(&mut self, target: ContrastTarget) -> bool {
+        self.ensure_contrast_with(target, &AnsiPalette::default())
+    }
+
+    ///Adjust the Oklab lightness of the foreground, keeping its hue, until it meets a contrast
+    ///target against the background. The smallest change which meets the target is used, and if
+    ///the target can't be met the foreground is moved as far from the background as possible.
+    ///
+    ///If the style is reversed, the background is adjusted instead as it is drawn as the text.
+    ///The adjusted color is an [Rgb] which serializes as a hex value. Returns whether the style was
+    ///changed.
+    pub fn ensure_contrast_with(&mut self, target: ContrastTarget, palette: &AnsiPalette) -> bool {
+        let reverse = self.attributes.reverse.0;
+        let (fg, bg) = (
+            palette.foreground(self.fg.as_ref()),
+            palette.background(self.bg.as_ref()),
+        );
+        let (text, background) = if reverse { (bg, fg) } else { (fg, bg) };
+        if target.is_met(&Contrast::new(&text, &background)) {
+            return false;
+        }
+
+        let start = Oklch::from_rgb(text.get_rgb());
+        let with_lightness = |l: f64| Rgb::new().rgb(Oklch { l, ..start }.to_rgb());
+        let meets = |l: f64| target.is_met(&Contrast::new(&with_lightness(l), &background));
+        //Search towards white and towards black for the closest lightness which meets the target
+        let closest = [1.0, 0.0]
+            .into_iter()
+            .filter(|end| meets(*end))
+            .map(|end| {
+                let (mut fail, mut pass) = (start.l, end);
+                for _ in 0..24 {
+                    let mid = (fail + pass) / 2.0;
+                    if meets(mid) {
+                        pass = mid;
+                    } else {
+                        fail = mid;
+                    }
+                }
+                pass
+            })
+            .min_by(|a, b| (a - start.l).abs().total_cmp(&(b - start.l).abs()));
+        let lightness = closest.unwrap_or_else(|| {
+            let (light, dark) = (with_lightness(1.0), with_lightness(0.0));
+            if wcag_contrast(&light, &background) >= wcag_contrast(&dark, &background) {
+                1.0
+            } else {
+                0.0
+            }
+        });
+
+        let mut adjusted = with_lightness(lightness);
+        adjusted.set_external_source(adjusted.to_hex());
+        if reverse {
+            self.bg = Some(adjusted.into());
+        } else {
+            self.fg = Some(adjusted.into());
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod contrast_tests {
+    use super::*;
+    use crate::{color::Fixed, style::Attributes};
+
+    #[test]
+    fn test_wcag_contrast() {
+        let (black, white) = (Rgb::new().rgb([0; 3]), Rgb::new().rgb([255; 3]));
+        assert!((wcag_contrast(&black, &white) - 21.0).abs() < 1e-9);
+        assert_eq!(wcag_contrast(&white, &white), 1.0);
+        let gray = Rgb::new().rgb([118; 3]);
+        assert!((wcag_contrast(&gray, &white) - 4.54).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_apca_contrast() {
+        let (black, white) = (Rgb::new().rgb([0; 3]), Rgb::new().rgb([255; 3]));
+        assert!((apca_contrast(&black, &white) - 106.04).abs() < 0.01);
+        assert!((apca_contrast(&white, &black) + 107.88).abs() < 0.01);
+        assert_eq!(apca_contrast(&white, &white), 0.0);
+    }
+
+    #[test]
+    fn test_readable_foreground() {
+        let yellow = Rgb::new().rgb([255, 255, 0]);
+        assert_eq!(black_or_white(&yellow).get_rgb(), [0; 3]);
+        let navy = Rgb::new().rgb([0, 0, 128]);
+        assert_eq!(black_or_white(&navy).get_rgb(), [255; 3]);
+        assert_eq!(readable_foreground(&navy, &[]), None);
+    }
+
+    #[test]
+    fn test_style_contrast() {
+        let palette = AnsiPalette::default();
+        let plain = Style::default().contrast_with(&palette);
+        assert!((plain.wcag - wcag_contrast(&Rgb::new().rgb([192; 3]), &Rgb::new())).abs() < 1e-9);
+
+        let style = Style::default().fg(Ansi::Black).bg(Fixed::new().code(231));
+        assert!(style.contrast().apca > 100.0);
+        let reversed = style.attributes(Attributes::new().reverse()).contrast();
+        assert!(reversed.apca < -100.0);
+
+        let light = AnsiPalette {
+            foreground: [0; 3],
+            background: [255; 3],
+            ..Default::default()
+        };
+        assert!(Style::default().contrast_with(&light).apca > 100.0);
+    }
+
+    #[test]
+    fn test_ensure_contrast() {
+        let target = ContrastTarget::Wcag(4.5);
+        let mut style = Style::default()
+            .fg(Rgb::new().rgb([60, 60, 140]))
+            .bg(Ansi::Black);
+        assert!(style.ensure_contrast(target));
+        let contrast = style.contrast();
+        assert!(target.is_met(&contrast) && contrast.wcag < 4.7);
+        let fg = style.fg.as_ref().unwrap().as_rgb().unwrap().get_rgb();
+        let hue = |rgb: [u8; 3]| Oklch::from_rgb(rgb).h;
+        assert!((hue(fg) - hue([60, 60, 140])).abs() < 3.0);
+        assert!(!style.ensure_contrast(target));
+
+        let mut reversed = Style::default()
+            .fg(Ansi::White)
+            .bg(Rgb::new().rgb([200, 200, 200]))
+            .attributes(Attributes::new().reverse());
+        assert!(reversed.ensure_contrast(ContrastTarget::Apca(60.0)));
+        assert!(reversed.contrast().apca.abs() >= 60.0);
+        assert_eq!(reversed.fg, Some(Ansi::White.into()));
+
+        let mut impossible = Style::default()
+            .fg(Rgb::new().rgb([130, 130, 130]))
+            .bg(Rgb::new().rgb([128, 128, 128]));
+        assert!(impossible.ensure_contrast(ContrastTarget::Wcag(21.0)));
+        assert_eq!(
+            impossible.fg.as_ref().unwrap().as_rgb().unwrap().get_rgb(),
+            [0; 3]
+        );
+    }
+}
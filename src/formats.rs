@@ -0,0 +1,52 @@
+/*!
+Conversions between [Style](crate::Style) and the color configuration formats of other tools.
+
+Each tool lives in its own module. Where a format can express it, styles can be both exported to
+and imported from the tool's syntax.
+*/
+
+use crate::color::ColorFromStrError;
+
+///`EZA_COLORS` and `LS_COLORS`
+pub mod eza;
+
+///fzf `--color` option strings
+pub mod fzf;
+
+///ripgrep `--colors` directives and `GREP_COLORS`
+pub mod grep;
+
+///`JQ_COLORS`
+pub mod jq;
+
+///`LESS_TERMCAP_*` variables
+pub mod less;
+
+///ANSI SGR escape sequences
+pub mod sgr;
+
+///bash, zsh and fish prompt escapes
+pub mod shell;
+
+///Vim and Neovim `:highlight` commands
+pub mod vim;
+
+///Starship prompt style strings
+pub mod starship;
+
+///This enum defines various errors that may be encountered when parsing a style from another tool's format
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StyleFromStrError {
+    ///A color value could not be parsed
+    InvalidColor(ColorFromStrError),
+    ///An attribute or style keyword was not recognised
+    InvalidAttribute,
+    ///The string does not follow the syntax of the format
+    InvalidSyntax,
+}
+
+impl From<ColorFromStrError> for StyleFromStrError {
+    fn from(value: ColorFromStrError) -> Self {
+        Self::InvalidColor(value)
+    }
+}
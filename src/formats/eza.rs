@@ -0,0 +1,124 @@
+/*!
+The `EZA_COLORS` environment variable (a superset of `LS_COLORS`).
+
+Entries are `key=parameters` pairs separated by colons, where a key is either a two letter code
+(`di` for directories, `ur` for the user read permission bit, ...) or a glob pattern (`*.rs`).
+Values are SGR parameters.
+```rust
+use std::str::FromStr;
+use antsee::{formats::eza::EzaColors, Ansi};
+
+let colors = EzaColors::from_str("reset:di=1;34:*.rs=38;5;208").unwrap();
+assert!(colors.reset);
+assert_eq!(colors.get("di").unwrap().fg, Some(Ansi::Blue.into()));
+assert_eq!(colors.to_string(), "reset:di=1;34:*.rs=38;5;208");
+```
+*/
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::style::Style;
+
+use super::{sgr, StyleFromStrError};
+
+///Styles of eza's output, keyed by two letter codes or glob patterns
+#[derive(Clone, Debug, Default)]
+pub struct EzaColors {
+    ///Whether eza's built-in extension colors are disabled (`reset`)
+    pub reset: bool,
+    ///Styles of the keys, in the order they are written
+    pub entries: Vec<(String, Style)>,
+}
+
+impl EzaColors {
+    ///Create an empty set of eza colors
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///Set the style of a key, replacing any previous style
+    pub fn entry(mut self, key: &str, style: Style) -> Self {
+        self.set(key, style);
+        self
+    }
+
+    ///Set the style of a key, replacing any previous style
+    pub fn set(&mut self, key: &str, style: Style) {
+        match self.entries.iter_mut().find(|(k, _)| k == key) {
+            Some(existing) => existing.1 = style,
+            None => self.entries.push((key.to_string(), style)),
+        }
+    }
+
+    ///Get the style of a key
+    pub fn get(&self, key: &str) -> Option<&Style> {
+        self.entries.iter().find(|(k, _)| k == key).map(|(_, s)| s)
+    }
+}
+
+impl FromStr for EzaColors {
+    type Err = StyleFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut colors = EzaColors::new();
+        for entry in s.split(':').filter(|e| !e.is_empty()) {
+            if entry == "reset" {
+                colors.reset = true;
+                continue;
+            }
+            let (key, value) = entry
+                .split_once('=')
+                .ok_or(StyleFromStrError::InvalidSyntax)?;
+            if key.is_empty() {
+                return Err(StyleFromStrError::InvalidSyntax);
+            }
+            colors.set(key, sgr::parse(value)?);
+        }
+        Ok(colors)
+    }
+}
+
+impl Display for EzaColors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut entries: Vec<String> = self
+            .entries
+            .iter()
+            .map(|(key, style)| format!("{}={}", key, sgr::parameters(style)))
+            .collect();
+        if self.reset {
+            entries.insert(0, "reset".to_string());
+        }
+        write!(f, "{}", entries.join(":"))
+    }
+}
+
+#[cfg(test)]
+mod eza_tests {
+    use super::*;
+    use crate::{
+        color::{Ansi, Rgb},
+        style::Attributes,
+    };
+
+    #[test]
+    fn test_generate() {
+        let colors = EzaColors::new()
+            .entry("ur", Style::default().fg(Ansi::Yellow))
+            .entry(
+                "*.md",
+                Style::default()
+                    .fg(Rgb::new().rgb([1, 2, 3]))
+                    .attributes(Attributes::new().underline()),
+            );
+        assert_eq!(colors.to_string(), "ur=33:*.md=4;38;2;1;2;3");
+    }
+
+    #[test]
+    fn test_parse_ls_colors() {
+        let colors = EzaColors::from_str("di=01;34:ln=01;36:ex=01;32:").unwrap();
+        assert!(!colors.reset);
+        assert_eq!(colors.entries.len(), 3);
+        assert!(colors.get("ex").unwrap().attributes.bold.0);
+        assert!(EzaColors::from_str("di").is_err());
+    }
+}
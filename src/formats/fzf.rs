@@ -0,0 +1,346 @@
+/*!
+fzf `--color` option strings.
+
+An fzf color spec assigns a color and attributes to named slots of the interface:
+```rust
+use std::str::FromStr;
+use antsee::formats::fzf::{FzfColors, FzfSlot};
+
+let colors = FzfColors::from_str("fg:#d0d0d0,bg:-1,hl:214:bold").unwrap();
+let hl = colors.get(FzfSlot::Hl).unwrap();
+assert!(hl.attributes.bold.0);
+assert_eq!(colors.to_string(), "fg:#d0d0d0,bg:-1,hl:214:bold");
+```
+
+Slots which color the background (e.g. `bg`, `bg+`, `gutter`) read and write the `bg` of their
+[Style], all other slots use `fg`. `-1` is the terminal default color ([Ansi::Default]).
+*/
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::{
+    color::{Ansi, Color, ColorFromStrError, Fixed, Rgb},
+    style::{Attributes, Style},
+};
+
+use super::StyleFromStrError;
+
+macro_rules! fzf_slots {
+    ($($variant:ident, $name:expr, $background:expr, $doc:expr);* $(;)?) => {
+        ///A named part of the fzf interface which can be colored
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum FzfSlot {
+            $(
+                #[doc = $doc]
+                $variant,
+            )*
+        }
+
+        impl FzfSlot {
+            ///Get the name of the slot used in fzf color specs
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $name,)*
+                }
+            }
+
+            ///Get a slot by name
+            pub fn get_name(name: &str) -> Option<Self> {
+                match name {
+                    $($name => Some(Self::$variant),)*
+                    "current-fg" => Some(Self::FgSelected),
+                    "current-bg" => Some(Self::BgSelected),
+                    "current-hl" => Some(Self::HlSelected),
+                    "input" => Some(Self::Query),
+                    "prompt-fg" => Some(Self::Prompt),
+                    _ => None,
+                }
+            }
+
+            ///Returns whether the slot colors the background
+            pub fn is_background(&self) -> bool {
+                match self {
+                    $(Self::$variant => $background,)*
+                }
+            }
+        }
+    };
+}
+
+fzf_slots! {
+    Fg, "fg", false, "Text";
+    Bg, "bg", true, "Background";
+    PreviewFg, "preview-fg", false, "Preview window text";
+    PreviewBg, "preview-bg", true, "Preview window background";
+    Hl, "hl", false, "Highlighted substrings";
+    FgSelected, "fg+", false, "Text of the current line";
+    BgSelected, "bg+", true, "Background of the current line";
+    Gutter, "gutter", true, "Gutter on the left";
+    HlSelected, "hl+", false, "Highlighted substrings of the current line";
+    Query, "query", false, "Query string";
+    Disabled, "disabled", false, "Query string when search is disabled";
+    Info, "info", false, "Info line (match counters)";
+    Border, "border", false, "Border around the window";
+    Separator, "separator", false, "Horizontal separator on the info line";
+    Scrollbar, "scrollbar", false, "Scrollbar";
+    PreviewBorder, "preview-border", false, "Border around the preview window";
+    PreviewScrollbar, "preview-scrollbar", false, "Scrollbar of the preview window";
+    Label, "label", false, "Border label";
+    PreviewLabel, "preview-label", false, "Border label of the preview window";
+    Prompt, "prompt", false, "Prompt";
+    Pointer, "pointer", false, "Pointer to the current line";
+    Marker, "marker", false, "Multi-select marker";
+    Spinner, "spinner", false, "Streaming input indicator";
+    Header, "header", false, "Header";
+}
+
+///A set of fzf slot styles, optionally on top of a base color scheme
+#[derive(Clone, Debug, Default)]
+pub struct FzfColors {
+    ///Base color scheme (`dark`, `light`, `16` or `bw`)
+    pub base: Option<String>,
+    ///Styles of the slots, in the order they are written
+    pub slots: Vec<(FzfSlot, Style)>,
+}
+
+impl FzfColors {
+    ///Create an empty set of fzf colors
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///Set the base color scheme
+    pub fn base(mut self, base: &str) -> Self {
+        self.base = Some(base.to_string());
+        self
+    }
+
+    ///Set the style of a slot, replacing any previous style
+    pub fn slot(mut self, slot: FzfSlot, style: Style) -> Self {
+        self.set(slot, style);
+        self
+    }
+
+    ///Set the style of a slot, replacing any previous style
+    pub fn set(&mut self, slot: FzfSlot, style: Style) {
+        match self.slots.iter_mut().find(|(s, _)| *s == slot) {
+            Some(existing) => existing.1 = style,
+            None => self.slots.push((slot, style)),
+        }
+    }
+
+    ///Get the style of a slot
+    pub fn get(&self, slot: FzfSlot) -> Option<&Style> {
+        self.slots.iter().find(|(s, _)| *s == slot).map(|(_, s)| s)
+    }
+
+    ///Format the colors as a full command line option (e.g. for `FZF_DEFAULT_OPTS`)
+    pub fn to_option(&self) -> String {
+        format!("--color={}", self)
+    }
+}
+
+const ANSI_NAMES: [&str; 16] = [
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "magenta",
+    "cyan",
+    "white",
+    "bright-black",
+    "bright-red",
+    "bright-green",
+    "bright-yellow",
+    "bright-blue",
+    "bright-magenta",
+    "bright-cyan",
+    "bright-white",
+];
+
+fn format_color(color: &Color) -> String {
+    match color {
+        Color::Ansi(ansi) => match ansi.index() {
+            Some(index) => index.to_string(),
+            None => "-1".to_string(),
+        },
+        Color::Fixed(fixed) => fixed.0.to_string(),
+        Color::Rgb(rgb) => rgb.to_hex(),
+    }
+}
+
+fn parse_color(s: &str) -> Option<Result<Color, ColorFromStrError>> {
+    if s == "-1" {
+        return Some(Ok(Ansi::Default.into()));
+    }
+    if let Ok(code) = s.parse::<u8>() {
+        return Some(Ok(Fixed::new().code(code).into()));
+    }
+    if s.starts_with('#') {
+        return Some(Rgb::from_str(s).map(Color::from));
+    }
+    ANSI_NAMES
+        .iter()
+        .position(|name| *name == s)
+        .and_then(|i| Ansi::from_index(i as u8))
+        .map(|ansi| Ok(ansi.into()))
+}
+
+fn parse_slot(spec: &str) -> Result<(FzfSlot, Style), StyleFromStrError> {
+    let mut parts = spec.split(':');
+    let name = parts.next().unwrap_or_default();
+    let slot = FzfSlot::get_name(name).ok_or(StyleFromStrError::InvalidSyntax)?;
+    let mut color = None;
+    let mut attributes = Attributes::new();
+    for part in parts {
+        match part {
+            "regular" => attributes.reset(),
+            "bold" => attributes = attributes.bold(),
+            "dim" => attributes = attributes.dim(),
+            "italic" => attributes = attributes.italic(),
+            "underline" => attributes = attributes.underline(),
+            "blink" => attributes = attributes.blink(),
+            "reverse" => attributes = attributes.reverse(),
+            "strikethrough" => attributes = attributes.strikethrough(),
+            _ => match parse_color(part) {
+                Some(parsed) => color = Some(parsed?),
+                None => return Err(StyleFromStrError::InvalidAttribute),
+            },
+        }
+    }
+    let mut style = Style::default().attributes(attributes);
+    if slot.is_background() {
+        style.bg = color;
+    } else {
+        style.fg = color;
+    }
+    Ok((slot, style))
+}
+
+impl FromStr for FzfColors {
+    type Err = StyleFromStrError;
+
+    ///Parse an fzf color spec, with or without the leading `--color=`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s
+            .strip_prefix("--color=")
+            .or_else(|| s.strip_prefix("--color "))
+            .unwrap_or(s)
+            .trim_matches(|c| c == '\'' || c == '"');
+        let mut colors = FzfColors::new();
+        for spec in s.split(',').map(str::trim).filter(|spec| !spec.is_empty()) {
+            if !spec.contains(':') {
+                match spec {
+                    "dark" | "light" | "16" | "bw" => colors.base = Some(spec.to_string()),
+                    _ => return Err(StyleFromStrError::InvalidSyntax),
+                }
+                continue;
+            }
+            let (slot, style) = parse_slot(spec)?;
+            colors.set(slot, style);
+        }
+        Ok(colors)
+    }
+}
+
+impl Display for FzfColors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut specs = Vec::new();
+        if let Some(base) = &self.base {
+            specs.push(base.clone());
+        }
+        for (slot, style) in &self.slots {
+            let color = if slot.is_background() {
+                &style.bg
+            } else {
+                &style.fg
+            };
+            let mut parts = vec![slot.name().to_string()];
+            if let Some(color) = color.as_ref().filter(|color| color.is_resolved()) {
+                parts.push(format_color(color));
+            }
+            let attributes = &style.attributes;
+            for (attribute, name) in [
+                (attributes.bold, "bold"),
+                (attributes.dimmed, "dim"),
+                (attributes.italic, "italic"),
+                (attributes.underline, "underline"),
+                (attributes.blink, "blink"),
+                (attributes.reverse, "reverse"),
+                (attributes.strikethrough, "strikethrough"),
+            ] {
+                if attribute.0 {
+                    parts.push(name.to_string());
+                }
+            }
+            if parts.len() > 1 {
+                specs.push(parts.join(":"));
+            }
+        }
+        write!(f, "{}", specs.join(","))
+    }
+}
+
+#[cfg(test)]
+mod fzf_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_spec() {
+        let colors = FzfColors::from_str(
+            "--color=dark,fg:-1,bg:-1,hl:#5f87af,fg+:red:bold,bg+:236,gutter:-1,pointer:#af5fff:italic",
+        )
+        .unwrap();
+        assert_eq!(colors.base.as_deref(), Some("dark"));
+        assert_eq!(
+            colors.get(FzfSlot::Bg).unwrap().bg,
+            Some(Ansi::Default.into())
+        );
+        assert_eq!(colors.get(FzfSlot::Bg).unwrap().fg, None);
+        assert_eq!(
+            colors.get(FzfSlot::BgSelected).unwrap().bg,
+            Some(Fixed::new().code(236).into())
+        );
+        let current = colors.get(FzfSlot::FgSelected).unwrap();
+        assert_eq!(current.fg, Some(Ansi::Red.into()));
+        assert!(current.attributes.bold.0);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            FzfColors::from_str("highlight:214").unwrap_err(),
+            StyleFromStrError::InvalidSyntax
+        );
+        assert_eq!(
+            FzfColors::from_str("hl:214:shiny").unwrap_err(),
+            StyleFromStrError::InvalidAttribute
+        );
+        assert!(FzfColors::from_str("hl:#12").is_err());
+    }
+
+    #[test]
+    fn test_generate_from_styles() {
+        let colors = FzfColors::new()
+            .slot(
+                FzfSlot::Fg,
+                Style::default().fg(Rgb::new().rgb([208, 208, 208])),
+            )
+            .slot(
+                FzfSlot::Gutter,
+                Style::default().fg(Ansi::Red).bg(Ansi::Default),
+            )
+            .slot(
+                FzfSlot::HlSelected,
+                Style::default()
+                    .fg(Ansi::BrightYellow)
+                    .attributes(Attributes::new().bold().underline()),
+            );
+        assert_eq!(
+            colors.to_option(),
+            "--color=fg:#d0d0d0,gutter:-1,hl+:11:bold:underline"
+        );
+    }
+}
@@ -0,0 +1,508 @@
+/*!
+ripgrep `--colors` directives and the `GREP_COLORS` environment variable.
+
+```rust
+use std::str::FromStr;
+use antsee::formats::grep::{GrepColors, GrepSlot, RipgrepColors, RipgrepSlot};
+
+let ripgrep = RipgrepColors::from_str("--colors 'match:fg:0x33,0x66,0xff' --colors 'match:style:bold'")
+    .unwrap();
+let matched = ripgrep.get(RipgrepSlot::Match).unwrap();
+assert!(matched.attributes.bold.0);
+
+let grep = GrepColors::new().slot(GrepSlot::SelectedMatch, matched.clone());
+assert_eq!(grep.to_string(), "ms=1;38;2;51;102;255");
+```
+*/
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::{
+    color::{Ansi, Color, ColorFromStrError, Fixed, Rgb},
+    style::Style,
+};
+
+use super::{sgr, StyleFromStrError};
+
+///A part of ripgrep's output which can be colored
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RipgrepSlot {
+    ///File paths
+    Path,
+    ///Line numbers
+    Line,
+    ///Column numbers
+    Column,
+    ///Matched text
+    Match,
+}
+
+impl RipgrepSlot {
+    ///Get the name of the slot used in `--colors` directives
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Path => "path",
+            Self::Line => "line",
+            Self::Column => "column",
+            Self::Match => "match",
+        }
+    }
+
+    ///Get a slot by name
+    pub fn get_name(name: &str) -> Option<Self> {
+        match name {
+            "path" => Some(Self::Path),
+            "line" => Some(Self::Line),
+            "column" => Some(Self::Column),
+            "match" => Some(Self::Match),
+            _ => None,
+        }
+    }
+}
+
+///Styles of ripgrep's output, converted to and from `--colors` directives
+#[derive(Clone, Debug, Default)]
+pub struct RipgrepColors {
+    slots: Vec<(RipgrepSlot, Style)>,
+    ///Slots with the `intense` style applied by a directive, which is kept in sync with `slots`
+    ///by only changing them through [RipgrepColors::set]
+    intense: Vec<RipgrepSlot>,
+}
+
+const RIPGREP_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+///Format a color, writing bright [Ansi] colors as a name if `intense` is used or as their ANSI256
+///index otherwise
+fn format_ripgrep_color(color: &Color, intense: bool) -> Option<String> {
+    match color {
+        Color::Ansi(ansi) => ansi.index().map(|i| match i >= 8 && !intense {
+            true => i.to_string(),
+            false => RIPGREP_NAMES[i as usize % 8].to_string(),
+        }),
+        Color::Fixed(fixed) => Some(fixed.0.to_string()),
+        Color::Rgb(rgb) if !rgb.is_resolved() => None,
+        Color::Rgb(rgb) => {
+            let [r, g, b] = rgb.get_rgb();
+            Some(format!("0x{:02x},0x{:02x},0x{:02x}", r, g, b))
+        }
+    }
+}
+
+fn parse_ripgrep_component(s: &str) -> Result<u8, ColorFromStrError> {
+    match s.strip_prefix("0x") {
+        Some(hex) => u8::from_str_radix(hex, 16),
+        None => s.parse(),
+    }
+    .map_err(|_| ColorFromStrError::InvalidValue)
+}
+
+fn parse_ripgrep_color(s: &str) -> Result<Color, StyleFromStrError> {
+    if let Some(index) = RIPGREP_NAMES.iter().position(|name| *name == s) {
+        return Ok(Ansi::from_index(index as u8).unwrap_or_default().into());
+    }
+    let components: Vec<&str> = s.split(',').collect();
+    match components[..] {
+        [code] => Ok(Fixed::new().code(parse_ripgrep_component(code)?).into()),
+        [r, g, b] => Ok(Rgb::new()
+            .rgb([
+                parse_ripgrep_component(r)?,
+                parse_ripgrep_component(g)?,
+                parse_ripgrep_component(b)?,
+            ])
+            .into()),
+        _ => Err(ColorFromStrError::InvalidValue.into()),
+    }
+}
+
+///Make an [Ansi] color bright if `intense` is set, or its normal variant otherwise
+fn set_intensity(color: &mut Option<Color>, intense: bool) {
+    if let Some(Color::Ansi(ansi)) = color {
+        if let Some(index) = ansi.index() {
+            let index = index % 8 + if intense { 8 } else { 0 };
+            *ansi = Ansi::from_index(index).unwrap_or(*ansi);
+        }
+    }
+}
+
+impl RipgrepColors {
+    ///Create an empty set of ripgrep colors
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///Set the style of a slot, replacing any previous style
+    pub fn slot(mut self, slot: RipgrepSlot, style: Style) -> Self {
+        self.set(slot, style);
+        self
+    }
+
+    ///Set the style of a slot, replacing any previous style
+    pub fn set(&mut self, slot: RipgrepSlot, style: Style) {
+        self.intense.retain(|s| *s != slot);
+        match self.slots.iter_mut().find(|(s, _)| *s == slot) {
+            Some(existing) => existing.1 = style,
+            None => self.slots.push((slot, style)),
+        }
+    }
+
+    ///Get the style of a slot
+    pub fn get(&self, slot: RipgrepSlot) -> Option<&Style> {
+        self.slots.iter().find(|(s, _)| *s == slot).map(|(_, s)| s)
+    }
+
+    ///Iterate over the slots and their styles, in the order they are written
+    pub fn slots(&self) -> impl Iterator<Item = (RipgrepSlot, &Style)> {
+        self.slots.iter().map(|(slot, style)| (*slot, style))
+    }
+
+    ///Get the `--colors` directives (e.g. `match:fg:0x33,0x66,0xff`). Each slot is cleared with
+    ///`none` first so ripgrep's defaults do not leak into the style.
+    ///
+    ///Bright [Ansi] colors are written with the `intense` style, which ripgrep applies to both the
+    ///foreground and background. If only one of them is bright, it is written as its ANSI256 index
+    ///instead. Only the bold, italic and underline attributes are supported.
+    pub fn directives(&self) -> Vec<String> {
+        let mut directives = Vec::new();
+        for (slot, style) in &self.slots {
+            let name = slot.name();
+            directives.push(format!("{}:none", name));
+            let indices: Vec<u8> = [&style.fg, &style.bg]
+                .into_iter()
+                .filter_map(|color| match color {
+                    Some(Color::Ansi(ansi)) => ansi.index(),
+                    _ => None,
+                })
+                .collect();
+            let intense = !indices.is_empty() && indices.iter().all(|index| *index >= 8);
+            for (kind, color) in [("fg", &style.fg), ("bg", &style.bg)] {
+                let formatted = color
+                    .as_ref()
+                    .and_then(|color| format_ripgrep_color(color, intense));
+                if let Some(color) = formatted {
+                    directives.push(format!("{}:{}:{}", name, kind, color));
+                }
+            }
+            let attributes = &style.attributes;
+            for (attribute, value) in [
+                (attributes.bold.0, "bold"),
+                (attributes.italic.0, "italic"),
+                (attributes.underline.0, "underline"),
+                (intense, "intense"),
+            ] {
+                if attribute {
+                    directives.push(format!("{}:style:{}", name, value));
+                }
+            }
+        }
+        directives
+    }
+
+    ///Get the directives as `--colors=...` command line arguments, as used in ripgrep config files
+    pub fn to_arguments(&self) -> Vec<String> {
+        self.directives()
+            .iter()
+            .map(|directive| format!("--colors={}", directive))
+            .collect()
+    }
+
+    ///Apply a single `--colors` directive on top of the current styles. As in ripgrep, the
+    ///`intense` style applies to the colors of the slot whichever order they are set in, until it
+    ///is cleared with `nointense` or `none`.
+    pub fn apply(&mut self, directive: &str) -> Result<(), StyleFromStrError> {
+        let parts: Vec<&str> = directive.splitn(3, ':').collect();
+        let slot = parts
+            .first()
+            .and_then(|name| RipgrepSlot::get_name(name))
+            .ok_or(StyleFromStrError::InvalidSyntax)?;
+        let mut style = self.get(slot).cloned().unwrap_or_default();
+        let mut intense = self.intense.contains(&slot);
+        match parts[1..] {
+            ["none"] => {
+                style = Style::default();
+                intense = false;
+            }
+            ["fg", color] => style.fg = Some(parse_ripgrep_color(color)?),
+            ["bg", color] => style.bg = Some(parse_ripgrep_color(color)?),
+            ["style", value] => {
+                let attributes = &mut style.attributes;
+                match value {
+                    "bold" => attributes.set_bold(true),
+                    "nobold" => attributes.set_bold(false),
+                    "italic" => attributes.set_italic(true),
+                    "noitalic" => attributes.set_italic(false),
+                    "underline" => attributes.set_underline(true),
+                    "nounderline" => attributes.set_underline(false),
+                    "intense" => intense = true,
+                    "nointense" => {
+                        //Bright colors of the slot only come from the intense style
+                        intense = false;
+                        set_intensity(&mut style.fg, false);
+                        set_intensity(&mut style.bg, false);
+                    }
+                    _ => return Err(StyleFromStrError::InvalidAttribute),
+                }
+            }
+            _ => return Err(StyleFromStrError::InvalidSyntax),
+        }
+        if intense {
+            set_intensity(&mut style.fg, true);
+            set_intensity(&mut style.bg, true);
+        }
+        self.set(slot, style);
+        if intense {
+            self.intense.push(slot);
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for RipgrepColors {
+    type Err = StyleFromStrError;
+
+    ///Parse whitespace separated directives. Each may be preceded by `--colors`, and may be quoted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut colors = RipgrepColors::new();
+        for word in s.split_whitespace() {
+            let word = word.strip_prefix("--colors=").unwrap_or(word);
+            let directive = word.trim_matches(|c| c == '\'' || c == '"');
+            if directive == "--colors" {
+                continue;
+            }
+            colors.apply(directive)?;
+        }
+        Ok(colors)
+    }
+}
+
+///A part of grep's output which can be colored with `GREP_COLORS`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GrepSlot {
+    ///Matched text in selected lines (`ms`)
+    SelectedMatch,
+    ///Matched text in context lines (`mc`)
+    ContextMatch,
+    ///Selected lines (`sl`)
+    SelectedLine,
+    ///Context lines (`cx`)
+    ContextLine,
+    ///File names (`fn`)
+    FileName,
+    ///Line numbers (`ln`)
+    LineNumber,
+    ///Byte offsets (`bn`)
+    ByteOffset,
+    ///Separators (`se`)
+    Separator,
+}
+
+impl GrepSlot {
+    ///Get the two letter capability name of the slot
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::SelectedMatch => "ms",
+            Self::ContextMatch => "mc",
+            Self::SelectedLine => "sl",
+            Self::ContextLine => "cx",
+            Self::FileName => "fn",
+            Self::LineNumber => "ln",
+            Self::ByteOffset => "bn",
+            Self::Separator => "se",
+        }
+    }
+
+    ///Get a slot by capability name
+    pub fn get_name(name: &str) -> Option<Self> {
+        match name {
+            "ms" => Some(Self::SelectedMatch),
+            "mc" => Some(Self::ContextMatch),
+            "sl" => Some(Self::SelectedLine),
+            "cx" => Some(Self::ContextLine),
+            "fn" => Some(Self::FileName),
+            "ln" => Some(Self::LineNumber),
+            "bn" => Some(Self::ByteOffset),
+            "se" => Some(Self::Separator),
+            _ => None,
+        }
+    }
+}
+
+///Styles of grep's output, converted to and from the `GREP_COLORS` format
+#[derive(Clone, Debug, Default)]
+pub struct GrepColors {
+    ///Styles of the slots, in the order they are written
+    pub slots: Vec<(GrepSlot, Style)>,
+    ///Whether `sl` and `cx` swap meaning when `-v` is used (`rv`)
+    pub reverse: bool,
+    ///Whether grep skips clearing to the end of the line (`ne`)
+    pub no_erase: bool,
+}
+
+impl GrepColors {
+    ///Create an empty set of grep colors
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///Set the style of a slot, replacing any previous style
+    pub fn slot(mut self, slot: GrepSlot, style: Style) -> Self {
+        self.set(slot, style);
+        self
+    }
+
+    ///Set the style of a slot, replacing any previous style
+    pub fn set(&mut self, slot: GrepSlot, style: Style) {
+        match self.slots.iter_mut().find(|(s, _)| *s == slot) {
+            Some(existing) => existing.1 = style,
+            None => self.slots.push((slot, style)),
+        }
+    }
+
+    ///Get the style of a slot
+    pub fn get(&self, slot: GrepSlot) -> Option<&Style> {
+        self.slots.iter().find(|(s, _)| *s == slot).map(|(_, s)| s)
+    }
+}
+
+impl FromStr for GrepColors {
+    type Err = StyleFromStrError;
+
+    ///Parse a `GREP_COLORS` value. `mt` sets both `ms` and `mc`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut colors = GrepColors::new();
+        for capability in s.split(':').filter(|c| !c.is_empty()) {
+            match capability.split_once('=') {
+                Some(("mt", value)) => {
+                    let style = sgr::parse(value)?;
+                    colors.set(GrepSlot::SelectedMatch, style.clone());
+                    colors.set(GrepSlot::ContextMatch, style);
+                }
+                Some((name, value)) => {
+                    let slot = GrepSlot::get_name(name).ok_or(StyleFromStrError::InvalidSyntax)?;
+                    colors.set(slot, sgr::parse(value)?);
+                }
+                None => match capability {
+                    "rv" => colors.reverse = true,
+                    "ne" => colors.no_erase = true,
+                    _ => return Err(StyleFromStrError::InvalidSyntax),
+                },
+            }
+        }
+        Ok(colors)
+    }
+}
+
+impl Display for GrepColors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut capabilities: Vec<String> = self
+            .slots
+            .iter()
+            .map(|(slot, style)| format!("{}={}", slot.name(), sgr::parameters(style)))
+            .collect();
+        if self.reverse {
+            capabilities.push("rv".to_string());
+        }
+        if self.no_erase {
+            capabilities.push("ne".to_string());
+        }
+        write!(f, "{}", capabilities.join(":"))
+    }
+}
+
+#[cfg(test)]
+mod grep_tests {
+    use super::*;
+    use crate::style::Attributes;
+
+    #[test]
+    fn test_ripgrep_export() {
+        let colors = RipgrepColors::new()
+            .slot(
+                RipgrepSlot::Match,
+                Style::default()
+                    .fg(Rgb::new().rgb([0x33, 0x66, 0xff]))
+                    .attributes(Attributes::new().bold()),
+            )
+            .slot(RipgrepSlot::Path, Style::default().fg(Ansi::BrightMagenta));
+        assert_eq!(
+            colors.directives(),
+            [
+                "match:none",
+                "match:fg:0x33,0x66,0xff",
+                "match:style:bold",
+                "path:none",
+                "path:fg:magenta",
+                "path:style:intense",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ripgrep_export_mixed_intensity() {
+        let colors = RipgrepColors::new().slot(
+            RipgrepSlot::Line,
+            Style::default().fg(Ansi::Red).bg(Ansi::BrightBlue),
+        );
+        assert_eq!(
+            colors.directives(),
+            ["line:none", "line:fg:red", "line:bg:12"]
+        );
+        let reparsed = RipgrepColors::from_str(&colors.directives().join(" ")).unwrap();
+        let line = reparsed.get(RipgrepSlot::Line).unwrap();
+        assert_eq!(line.fg, Some(Ansi::Red.into()));
+        assert_eq!(line.bg, Some(Fixed::new().code(12).into()));
+        assert_eq!(reparsed.slots().count(), 1);
+    }
+
+    #[test]
+    fn test_ripgrep_import() {
+        let colors = RipgrepColors::from_str(
+            "--colors=line:fg:yellow --colors 'line:style:intense' --colors \"column:bg:208\" --colors 'path:fg:10,20,30' --colors path:style:underline",
+        )
+        .unwrap();
+        assert_eq!(
+            colors.get(RipgrepSlot::Line).unwrap().fg,
+            Some(Ansi::BrightYellow.into())
+        );
+        assert_eq!(
+            colors.get(RipgrepSlot::Column).unwrap().bg,
+            Some(Fixed::new().code(208).into())
+        );
+        let path = colors.get(RipgrepSlot::Path).unwrap();
+        assert_eq!(path.fg, Some(Rgb::new().rgb([10, 20, 30]).into()));
+        assert!(path.attributes.underline.0);
+
+        let intense_first = RipgrepColors::from_str("match:style:intense match:fg:red").unwrap();
+        let intense_last = RipgrepColors::from_str("match:fg:red match:style:intense").unwrap();
+        let expected = Some(Ansi::BrightRed.into());
+        assert_eq!(intense_first.get(RipgrepSlot::Match).unwrap().fg, expected);
+        assert_eq!(intense_last.get(RipgrepSlot::Match).unwrap().fg, expected);
+        let cleared =
+            RipgrepColors::from_str("match:style:intense match:fg:red match:style:nointense")
+                .unwrap();
+        let matched = cleared.get(RipgrepSlot::Match).unwrap();
+        assert_eq!(matched.fg, Some(Ansi::Red.into()));
+        let reset = RipgrepColors::from_str("match:style:intense match:none match:fg:red").unwrap();
+        assert_eq!(
+            reset.get(RipgrepSlot::Match).unwrap().fg,
+            Some(Ansi::Red.into())
+        );
+
+        assert!(RipgrepColors::from_str("match:fg:purple").is_err());
+        assert!(RipgrepColors::from_str("matches:fg:red").is_err());
+    }
+
+    #[test]
+    fn test_grep_colors() {
+        let colors = GrepColors::from_str("mt=01;31:sl=:cx=:fn=35:ln=32:bn=32:se=36:ne").unwrap();
+        let matched = colors.get(GrepSlot::ContextMatch).unwrap();
+        assert_eq!(matched.fg, Some(Ansi::Red.into()));
+        assert!(matched.attributes.bold.0);
+        assert!(colors.no_erase);
+        assert_eq!(
+            colors.to_string(),
+            "ms=1;31:mc=1;31:sl=:cx=:fn=35:ln=32:bn=32:se=36:ne"
+        );
+    }
+}
@@ -0,0 +1,152 @@
+/*!
+The positional `JQ_COLORS` environment variable.
+
+`JQ_COLORS` is a colon separated list of SGR parameters for each JSON value type, in the order of
+[JqSlot]:
+```rust
+use std::str::FromStr;
+use antsee::{formats::jq::{JqColors, JqSlot}, Ansi, Style};
+
+let colors = JqColors::new().slot(JqSlot::Strings, Style::default().fg(Ansi::BrightGreen));
+assert_eq!(colors.to_string(), "0;90:0;37:0;37:0;37:92");
+```
+Slots without a style are written with jq's default colors when a later slot is set.
+*/
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::style::Style;
+
+use super::{sgr, StyleFromStrError};
+
+///A JSON value type colored by jq, in `JQ_COLORS` order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum JqSlot {
+    ///`null`
+    Null,
+    ///`false`
+    False,
+    ///`true`
+    True,
+    ///Numbers
+    Numbers,
+    ///Strings
+    Strings,
+    ///Arrays
+    Arrays,
+    ///Objects
+    Objects,
+    ///Object keys (jq 1.7.1 and later)
+    ObjectKeys,
+}
+
+impl JqSlot {
+    const ALL: [JqSlot; 8] = [
+        Self::Null,
+        Self::False,
+        Self::True,
+        Self::Numbers,
+        Self::Strings,
+        Self::Arrays,
+        Self::Objects,
+        Self::ObjectKeys,
+    ];
+
+    ///Get the default SGR parameters jq uses for the slot
+    pub fn default_parameters(&self) -> &'static str {
+        match self {
+            Self::Null => "0;90",
+            Self::False | Self::True | Self::Numbers => "0;37",
+            Self::Strings => "0;32",
+            Self::Arrays | Self::Objects => "1;37",
+            Self::ObjectKeys => "34;1",
+        }
+    }
+}
+
+///Styles of jq's output
+#[derive(Clone, Debug, Default)]
+pub struct JqColors([Option<Style>; 8]);
+
+impl JqColors {
+    ///Create an empty set of jq colors
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///Set the style of a slot
+    pub fn slot(mut self, slot: JqSlot, style: Style) -> Self {
+        self.set(slot, style);
+        self
+    }
+
+    ///Set the style of a slot
+    pub fn set(&mut self, slot: JqSlot, style: Style) {
+        self.0[slot as usize] = Some(style);
+    }
+
+    ///Get the style of a slot
+    pub fn get(&self, slot: JqSlot) -> Option<&Style> {
+        self.0[slot as usize].as_ref()
+    }
+}
+
+impl FromStr for JqColors {
+    type Err = StyleFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split(':').collect();
+        if fields.len() > JqSlot::ALL.len() {
+            return Err(StyleFromStrError::InvalidSyntax);
+        }
+        let mut colors = JqColors::new();
+        for (slot, field) in JqSlot::ALL.iter().zip(fields) {
+            colors.set(*slot, sgr::parse(field)?);
+        }
+        Ok(colors)
+    }
+}
+
+impl Display for JqColors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let last = self.0.iter().rposition(Option::is_some);
+        let fields: Vec<String> = JqSlot::ALL
+            .iter()
+            .take(last.map_or(0, |i| i + 1))
+            .map(|slot| match self.get(*slot) {
+                Some(style) => sgr::parameters(style),
+                None => slot.default_parameters().to_string(),
+            })
+            .collect();
+        write!(f, "{}", fields.join(":"))
+    }
+}
+
+#[cfg(test)]
+mod jq_tests {
+    use super::*;
+    use crate::color::{Ansi, Fixed};
+
+    #[test]
+    fn test_parse() {
+        let colors = JqColors::from_str("1;30:0;39:0;39:0;39:0;32:1;39:1;39:38;5;208").unwrap();
+        assert_eq!(
+            colors.get(JqSlot::Null).unwrap().fg,
+            Some(Ansi::Black.into())
+        );
+        assert_eq!(
+            colors.get(JqSlot::ObjectKeys).unwrap().fg,
+            Some(Fixed::new().code(208).into())
+        );
+        assert!(JqColors::from_str("0:0:0:0:0:0:0:0:0").is_err());
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(JqColors::new().to_string(), "");
+        let colors = JqColors::new()
+            .slot(JqSlot::Null, Style::default().fg(Ansi::DarkGray))
+            .slot(JqSlot::ObjectKeys, Style::default().fg(Ansi::Cyan));
+        assert_eq!(colors.to_string(), "90:0;37:0;37:0;37:0;32:1;37:1;37:36");
+    }
+}
@@ -0,0 +1,207 @@
+/*!
+`LESS_TERMCAP_*` environment variables.
+
+less renders man pages and other overstruck text with termcap capabilities, which can be replaced
+with arbitrary escape sequences through environment variables. Each [LessTermcap] field replaces
+one kind of text:
+```rust
+use antsee::{formats::less::LessTermcap, Ansi, Attributes, Style};
+
+let termcap = LessTermcap {
+    bold: Some(Style::default().fg(Ansi::Red).attributes(Attributes::new().bold())),
+    ..Default::default()
+};
+assert_eq!(
+    termcap.env_vars(),
+    [
+        ("LESS_TERMCAP_md", "\x1b[1;31m".to_string()),
+        ("LESS_TERMCAP_me", "\x1b[0m".to_string()),
+    ]
+);
+```
+*/
+
+use crate::style::Style;
+
+use super::{sgr, StyleFromStrError};
+
+///Styles of the text modes less renders with termcap capabilities
+#[derive(Clone, Debug, Default)]
+pub struct LessTermcap {
+    ///Bold text, e.g. man page headings (`md`, ended by `me`)
+    pub bold: Option<Style>,
+    ///Blinking text (`mb`, ended by `me`)
+    pub blink: Option<Style>,
+    ///Dim text (`mh`, ended by `me`)
+    pub dim: Option<Style>,
+    ///Reversed text (`mr`, ended by `me`)
+    pub reverse: Option<Style>,
+    ///Underlined text, e.g. man page arguments (`us`, ended by `ue`)
+    pub underline: Option<Style>,
+    ///Standout text, e.g. the prompt and search matches (`so`, ended by `se`)
+    pub standout: Option<Style>,
+}
+
+///Parse one or more SGR sequences, such as the `\e[1m\e[31m` printed by `tput`, into one style
+fn parse_escape(value: &str) -> Result<Style, StyleFromStrError> {
+    let mut value = value
+        .trim_start_matches("$'")
+        .trim_end_matches('\'')
+        .to_string();
+    for introducer in ["\\e[", "\\E[", "\\033["] {
+        value = value.replace(introducer, "\x1b[");
+    }
+    let sequences = value
+        .strip_prefix("\x1b[")
+        .ok_or(StyleFromStrError::InvalidSyntax)?;
+    let parameters = sequences
+        .split("\x1b[")
+        .map(|sequence| match sequence.strip_suffix('m') {
+            //An empty parameter list resets, like 0
+            Some("") => Ok("0"),
+            Some(parameters) => Ok(parameters),
+            None => Err(StyleFromStrError::InvalidSyntax),
+        })
+        .collect::<Result<Vec<&str>, StyleFromStrError>>()?;
+    sgr::parse(&parameters.join(";"))
+}
+
+impl LessTermcap {
+    ///Get the environment variables which apply the styles. Each capability which starts a style
+    ///is followed by the capability that ends it.
+    pub fn env_vars(&self) -> Vec<(&'static str, String)> {
+        let mut vars = Vec::new();
+        for (style, start, end) in [
+            (&self.bold, "LESS_TERMCAP_md", "LESS_TERMCAP_me"),
+            (&self.blink, "LESS_TERMCAP_mb", "LESS_TERMCAP_me"),
+            (&self.dim, "LESS_TERMCAP_mh", "LESS_TERMCAP_me"),
+            (&self.reverse, "LESS_TERMCAP_mr", "LESS_TERMCAP_me"),
+            (&self.underline, "LESS_TERMCAP_us", "LESS_TERMCAP_ue"),
+            (&self.standout, "LESS_TERMCAP_so", "LESS_TERMCAP_se"),
+        ] {
+            if let Some(style) = style {
+                vars.push((start, sgr::escape(style)));
+                if !vars.iter().any(|(name, _)| *name == end) {
+                    vars.push((end, sgr::RESET.to_string()));
+                }
+            }
+        }
+        vars
+    }
+
+    ///Get the environment variables as shell `export` statements, using `$'...'` quoting
+    pub fn to_exports(&self) -> String {
+        self.env_vars()
+            .iter()
+            .map(|(name, value)| format!("export {}=$'{}'\n", name, value.replace('\x1b', "\\e")))
+            .collect()
+    }
+
+    ///Get the field a capability sets, or [None] for a capability which ends a style. Returns an
+    ///error for capabilities which are not supported.
+    fn field(&mut self, capability: &str) -> Result<Option<&mut Option<Style>>, StyleFromStrError> {
+        let field = match capability {
+            "md" => &mut self.bold,
+            "mb" => &mut self.blink,
+            "mh" => &mut self.dim,
+            "mr" => &mut self.reverse,
+            "us" => &mut self.underline,
+            "so" => &mut self.standout,
+            "me" | "ue" | "se" => return Ok(None),
+            _ => return Err(StyleFromStrError::InvalidSyntax),
+        };
+        Ok(Some(field))
+    }
+
+    ///Set a style from a `LESS_TERMCAP_*` variable. The value may contain a raw escape character
+    ///or a `\e`, `\E` or `\033` escape. Variables which end a style are ignored, and unsupported
+    ///capabilities are an error.
+    pub fn set_var(&mut self, name: &str, value: &str) -> Result<(), StyleFromStrError> {
+        if let Some(field) = self.field(name.trim_start_matches("LESS_TERMCAP_"))? {
+            *field = Some(parse_escape(value)?);
+        }
+        Ok(())
+    }
+
+    ///Read the styles from a list of environment variables, ignoring unrelated variables and
+    ///`LESS_TERMCAP_*` capabilities which are not supported (e.g. `ZN` or `ZV`)
+    pub fn from_env_vars<'a, I>(vars: I) -> Result<Self, StyleFromStrError>
+    where
+        I: IntoIterator<Item = (&'a str, &'a str)>,
+    {
+        let mut termcap = LessTermcap::default();
+        for (name, value) in vars {
+            let capability = match name.strip_prefix("LESS_TERMCAP_") {
+                Some(capability) => capability,
+                None => continue,
+            };
+            if let Ok(Some(field)) = termcap.field(capability) {
+                *field = Some(parse_escape(value)?);
+            }
+        }
+        Ok(termcap)
+    }
+}
+
+#[cfg(test)]
+mod less_tests {
+    use super::*;
+    use crate::{
+        color::{Ansi, Fixed},
+        style::Attributes,
+    };
+
+    #[test]
+    fn test_exports() {
+        let termcap = LessTermcap {
+            underline: Some(Style::default().fg(Fixed::new().code(71))),
+            standout: Some(
+                Style::default()
+                    .fg(Ansi::Black)
+                    .bg(Ansi::Yellow)
+                    .attributes(Attributes::new().bold()),
+            ),
+            ..Default::default()
+        };
+        assert_eq!(
+            termcap.to_exports(),
+            "export LESS_TERMCAP_us=$'\\e[38;5;71m'\n\
+             export LESS_TERMCAP_ue=$'\\e[0m'\n\
+             export LESS_TERMCAP_so=$'\\e[1;30;43m'\n\
+             export LESS_TERMCAP_se=$'\\e[0m'\n"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let termcap = LessTermcap::from_env_vars([
+            ("HOME", "/home/user"),
+            ("LESS_TERMCAP_md", "\x1b[01;31m"),
+            ("LESS_TERMCAP_me", "\x1b[0m"),
+            ("LESS_TERMCAP_us", "\\e[4;32m"),
+            ("LESS_TERMCAP_ZN", "\x1b[74m"),
+            ("LESS_TERMCAP_ZV", "\x1b[75m"),
+        ])
+        .unwrap();
+        assert_eq!(termcap.bold.unwrap().fg, Some(Ansi::Red.into()));
+        assert!(termcap.underline.unwrap().attributes.underline.0);
+        assert!(termcap.standout.is_none());
+
+        let termcap = LessTermcap::from_env_vars([
+            ("LESS_TERMCAP_md", "\x1b[1m\x1b[31m"),
+            ("LESS_TERMCAP_so", "\\e[1m\\e[33m\\e[44m"),
+        ])
+        .unwrap();
+        let bold = termcap.bold.unwrap();
+        assert_eq!(bold.fg, Some(Ansi::Red.into()));
+        assert!(bold.attributes.bold.0);
+        let standout = termcap.standout.unwrap();
+        assert_eq!(standout.fg, Some(Ansi::Yellow.into()));
+        assert_eq!(standout.bg, Some(Ansi::Blue.into()));
+        assert!(LessTermcap::from_env_vars([("LESS_TERMCAP_md", "\x1b[1m\x1b[31")]).is_err());
+
+        let mut termcap = LessTermcap::default();
+        assert!(termcap.set_var("LESS_TERMCAP_xx", "\x1b[1m").is_err());
+        assert!(termcap.set_var("LESS_TERMCAP_md", "bold").is_err());
+    }
+}
@@ -0,0 +1,207 @@
+/*!
+ANSI SGR (Select Graphic Rendition) escape sequences.
+
+Most terminal tools are configured with raw SGR parameters (`1;38;5;208`), so this module is the
+base of several of the other formats.
+```rust
+use antsee::{formats::sgr, Ansi, Attributes, Style};
+
+let style = Style::default().fg(Ansi::Red).attributes(Attributes::new().bold());
+assert_eq!(sgr::parameters(&style), "1;31");
+assert_eq!(sgr::paint(&style, "error"), "\x1b[1;31merror\x1b[0m");
+```
+*/
+
+use crate::{
+    color::{Ansi, Color, ColorFromStrError, Fixed, Rgb},
+    style::{Attributes, Style},
+};
+
+use super::StyleFromStrError;
+
+///The escape sequence which resets all colors and attributes
+pub const RESET: &str = "\x1b[0m";
+
+fn color_parameters(color: &Color, background: bool) -> String {
+    let extended = if background { 48 } else { 38 };
+    match color {
+        Color::Ansi(ansi) if background => ansi.background_code().to_string(),
+        Color::Ansi(ansi) => ansi.foreground_code().to_string(),
+        Color::Fixed(fixed) => format!("{};5;{}", extended, fixed.0),
+        Color::Rgb(rgb) => {
+            let [r, g, b] = rgb.get_rgb();
+            format!("{};2;{};{};{}", extended, r, g, b)
+        }
+    }
+}
+
+///Get the SGR codes of the attributes, in ascending order
+pub fn attribute_codes(attributes: &Attributes) -> Vec<u8> {
+    [
+        (attributes.bold, 1),
+        (attributes.dimmed, 2),
+        (attributes.italic, 3),
+        (attributes.underline, 4),
+        (attributes.blink, 5),
+        (attributes.reverse, 7),
+        (attributes.hidden, 8),
+        (attributes.strikethrough, 9),
+    ]
+    .iter()
+    .filter(|(attribute, _)| attribute.0)
+    .map(|(_, code)| *code)
+    .collect()
+}
+
+///Get the semicolon separated SGR parameters of a style (e.g. `1;38;5;208`). Attributes come
+///first, followed by the foreground and background colors. Unresolved colors are left out.
+pub fn parameters(style: &Style) -> String {
+    let mut parameters: Vec<String> = attribute_codes(&style.attributes)
+        .iter()
+        .map(|code| code.to_string())
+        .collect();
+    if let Some(fg) = style.fg.as_ref().filter(|fg| fg.is_resolved()) {
+        parameters.push(color_parameters(fg, false));
+    }
+    if let Some(bg) = style.bg.as_ref().filter(|bg| bg.is_resolved()) {
+        parameters.push(color_parameters(bg, true));
+    }
+    parameters.join(";")
+}
+
+///Get the escape sequence which applies a style. Returns an empty string for an empty style.
+pub fn escape(style: &Style) -> String {
+    let parameters = parameters(style);
+    if parameters.is_empty() {
+        return String::new();
+    }
+    format!("\x1b[{}m", parameters)
+}
+
+///Wrap text in the escape sequence of a style, followed by [RESET]
+pub fn paint(style: &Style, text: &str) -> String {
+    let escape = escape(style);
+    if escape.is_empty() {
+        return text.to_string();
+    }
+    format!("{}{}{}", escape, text, RESET)
+}
+
+fn parse_extended_color<'a, I>(codes: &mut I) -> Result<Color, StyleFromStrError>
+where
+    I: Iterator<Item = &'a str>,
+{
+    let mut next = || -> Result<u8, StyleFromStrError> {
+        codes
+            .next()
+            .and_then(|code| code.parse().ok())
+            .ok_or(StyleFromStrError::InvalidColor(
+                ColorFromStrError::InvalidValue,
+            ))
+    };
+    match next()? {
+        5 => Ok(Fixed::new().code(next()?).into()),
+        2 => Ok(Rgb::new().rgb([next()?, next()?, next()?]).into()),
+        _ => Err(StyleFromStrError::InvalidSyntax),
+    }
+}
+
+///Parse semicolon separated SGR parameters into a style. Parameters are applied in order, so
+///later codes override earlier ones and `0` resets the style.
+pub fn parse(parameters: &str) -> Result<Style, StyleFromStrError> {
+    let mut style = Style::default();
+    let mut codes = parameters
+        .split(';')
+        .map(str::trim)
+        .filter(|code| !code.is_empty());
+    while let Some(code) = codes.next() {
+        let code: u8 = code.parse().map_err(|_| StyleFromStrError::InvalidSyntax)?;
+        let attributes = &mut style.attributes;
+        match code {
+            0 => style = Style::default(),
+            1 => attributes.set_bold(true),
+            2 => attributes.set_dimmed(true),
+            3 => attributes.set_italic(true),
+            4 => attributes.set_underline(true),
+            5 | 6 => attributes.set_blink(true),
+            7 => attributes.set_reverse(true),
+            8 => attributes.set_hidden(true),
+            9 => attributes.set_strikethrough(true),
+            22 => {
+                attributes.set_bold(false);
+                attributes.set_dimmed(false);
+            }
+            23 => attributes.set_italic(false),
+            24 => attributes.set_underline(false),
+            25 => attributes.set_blink(false),
+            27 => attributes.set_reverse(false),
+            28 => attributes.set_hidden(false),
+            29 => attributes.set_strikethrough(false),
+            30..=37 => style.fg = Ansi::from_index(code - 30).map(Color::from),
+            90..=97 => style.fg = Ansi::from_index(code - 82).map(Color::from),
+            39 => style.fg = Some(Ansi::Default.into()),
+            40..=47 => style.bg = Ansi::from_index(code - 40).map(Color::from),
+            100..=107 => style.bg = Ansi::from_index(code - 92).map(Color::from),
+            49 => style.bg = Some(Ansi::Default.into()),
+            38 => style.fg = Some(parse_extended_color(&mut codes)?),
+            48 => style.bg = Some(parse_extended_color(&mut codes)?),
+            _ => return Err(StyleFromStrError::InvalidAttribute),
+        }
+    }
+    Ok(style)
+}
+
+#[cfg(test)]
+mod sgr_tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_parameters() {
+        let style = Style::default()
+            .fg(Fixed::new().code(208))
+            .bg(Rgb::new().rgb([1, 2, 3]))
+            .attributes(Attributes::new().underline().dim());
+        assert_eq!(parameters(&style), "2;4;38;5;208;48;2;1;2;3");
+
+        let style = Style::default().fg(Ansi::BrightBlue).bg(Ansi::Default);
+        assert_eq!(parameters(&style), "94;49");
+
+        let unresolved = Rgb::from_str("$accent").unwrap();
+        let style = Style::default().fg(unresolved).bg(Ansi::Blue);
+        assert_eq!(parameters(&style), "44");
+    }
+
+    #[test]
+    fn test_parse() {
+        let style = parse("01;38;5;208;48;2;1;2;3").unwrap();
+        assert_eq!(style.fg, Some(Fixed::new().code(208).into()));
+        assert_eq!(style.bg, Some(Rgb::new().rgb([1, 2, 3]).into()));
+        assert_eq!(style.attributes, Attributes::new().bold());
+
+        let style = parse("1;4;91;0;44;3").unwrap();
+        assert_eq!(style.fg, None);
+        assert_eq!(style.bg, Some(Ansi::Blue.into()));
+        assert_eq!(style.attributes, Attributes::new().italic());
+
+        assert!(parse("38;5").is_err());
+        assert!(parse("1;x").is_err());
+    }
+
+    #[test]
+    fn test_parse_overrides_parent() {
+        //The parameters of `\e[22m`
+        let parent = parse("1;2;4").unwrap();
+        let style = parse("22").unwrap().inherit(&parent);
+        assert!(!style.attributes.bold.0);
+        assert!(!style.attributes.dimmed.0);
+        assert!(style.attributes.underline.0);
+    }
+
+    #[test]
+    fn test_empty_style() {
+        assert_eq!(escape(&Style::default()), "");
+        assert_eq!(paint(&Style::default(), "text"), "text");
+    }
+}
@@ -0,0 +1,224 @@
+/*!
+Prompt escapes for bash, zsh and fish.
+
+```rust
+use antsee::{formats::shell::Shell, Ansi, Attributes, Style};
+
+let style = Style::default().fg(Ansi::Green).attributes(Attributes::new().bold());
+assert_eq!(Shell::Bash.start(&style), "\\[\\e[1;32m\\]");
+assert_eq!(Shell::Zsh.start(&style), "%B%F{2}");
+assert_eq!(Shell::Fish.start(&style), "set_color --bold green");
+```
+
+Each shell uses the most native syntax it supports for a color. Bash uses raw SGR parameters, zsh
+uses `%F`/`%K` with palette indices or hex values, and fish uses `set_color` with color names or hex
+values ([Fixed](crate::Fixed) colors are converted to hex as fish has no ANSI256 syntax).
+Attributes zsh has no escape for are written as raw SGR sequences inside `%{...%}`, containing the
+escape character itself rather than `\e`, which zsh doesn't decode in prompts.
+
+`set_color` has no options for strikethrough, blink or hidden text, so fish prompts leave those
+attributes out.
+*/
+
+use crate::{
+    color::{Ansi, Color},
+    style::Style,
+};
+
+use super::sgr;
+
+///A shell whose prompt can be styled
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Shell {
+    ///bash `PS1` escapes (`\[\e[...m\]`)
+    Bash,
+    ///zsh prompt escapes (`%F{...}%K{...}%B`)
+    Zsh,
+    ///fish `set_color` commands
+    Fish,
+}
+
+const FISH_NAMES: [&str; 16] = [
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "magenta",
+    "cyan",
+    "white",
+    "brblack",
+    "brred",
+    "brgreen",
+    "bryellow",
+    "brblue",
+    "brmagenta",
+    "brcyan",
+    "brwhite",
+];
+
+fn zsh_color(color: &Color, background: bool) -> String {
+    let value = match color {
+        Color::Ansi(Ansi::Default) => return if background { "%k" } else { "%f" }.to_string(),
+        Color::Ansi(ansi) => ansi.index().unwrap_or_default().to_string(),
+        Color::Fixed(fixed) => fixed.0.to_string(),
+        Color::Rgb(rgb) => rgb.to_hex(),
+    };
+    format!("%{}{{{}}}", if background { 'K' } else { 'F' }, value)
+}
+
+fn fish_color(color: &Color) -> String {
+    match color {
+        Color::Ansi(ansi) => match ansi.index() {
+            Some(index) => FISH_NAMES[index as usize].to_string(),
+            None => "normal".to_string(),
+        },
+        Color::Fixed(fixed) => fixed.to_rgb().to_hex()[1..].to_string(),
+        Color::Rgb(rgb) => rgb.to_hex()[1..].to_string(),
+    }
+}
+
+impl Shell {
+    ///Get the prompt code which applies a style. For fish this is a `set_color` command, which
+    ///should be used as a command substitution. Unresolved colors are left out.
+    pub fn start(&self, style: &Style) -> String {
+        match self {
+            Shell::Bash => {
+                let parameters = sgr::parameters(style);
+                if parameters.is_empty() {
+                    return String::new();
+                }
+                format!("\\[\\e[{}m\\]", parameters)
+            }
+            Shell::Zsh => {
+                let attributes = &style.attributes;
+                let mut prompt = String::new();
+                if attributes.bold.0 {
+                    prompt.push_str("%B");
+                }
+                if attributes.underline.0 {
+                    prompt.push_str("%U");
+                }
+                if attributes.reverse.0 {
+                    prompt.push_str("%S");
+                }
+                //zsh has no prompt escapes for the other attributes, so they are written raw. zsh
+                //doesn't decode `\e` in prompts, so the escape character itself is used.
+                let mut raw = attributes.clone();
+                raw.bold.0 = false;
+                raw.underline.0 = false;
+                raw.reverse.0 = false;
+                let raw = sgr::escape(&Style::default().attributes(raw));
+                if !raw.is_empty() {
+                    prompt.push_str(&format!("%{{{}%}}", raw));
+                }
+                if let Some(fg) = style.fg.as_ref().filter(|fg| fg.is_resolved()) {
+                    prompt.push_str(&zsh_color(fg, false));
+                }
+                if let Some(bg) = style.bg.as_ref().filter(|bg| bg.is_resolved()) {
+                    prompt.push_str(&zsh_color(bg, true));
+                }
+                prompt
+            }
+            Shell::Fish => {
+                let attributes = &style.attributes;
+                let mut arguments = vec!["set_color".to_string()];
+                //Strikethrough, blink and hidden have no `set_color` option and are left out
+                for (attribute, flag) in [
+                    (attributes.bold, "--bold"),
+                    (attributes.dimmed, "--dim"),
+                    (attributes.italic, "--italics"),
+                    (attributes.reverse, "--reverse"),
+                    (attributes.underline, "--underline"),
+                ] {
+                    if attribute.0 {
+                        arguments.push(flag.to_string());
+                    }
+                }
+                if let Some(bg) = style.bg.as_ref().filter(|bg| bg.is_resolved()) {
+                    arguments.push(format!("--background={}", fish_color(bg)));
+                }
+                if let Some(fg) = style.fg.as_ref().filter(|fg| fg.is_resolved()) {
+                    arguments.push(fish_color(fg));
+                }
+                if arguments.len() == 1 {
+                    return String::new();
+                }
+                arguments.join(" ")
+            }
+        }
+    }
+
+    ///Get the prompt code which resets all colors and attributes
+    pub fn reset(&self) -> String {
+        match self {
+            Shell::Bash => "\\[\\e[0m\\]".to_string(),
+            Shell::Zsh => "%b%u%s%f%k%{\x1b[0m%}".to_string(),
+            Shell::Fish => "set_color normal".to_string(),
+        }
+    }
+
+    ///Wrap text in the prompt codes of a style. For fish, the commands are wrapped in parentheses so
+    ///the result can be passed to `echo`.
+    pub fn paint(&self, style: &Style, text: &str) -> String {
+        let start = self.start(style);
+        if start.is_empty() {
+            return text.to_string();
+        }
+        match self {
+            Shell::Fish => format!("({}){}({})", start, text, self.reset()),
+            _ => format!("{}{}{}", start, text, self.reset()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod shell_tests {
+    use super::*;
+    use crate::{
+        color::{Fixed, Rgb},
+        style::Attributes,
+    };
+
+    #[test]
+    fn test_bash() {
+        let style = Style::default().fg(Fixed::new().code(208)).bg(Ansi::Blue);
+        assert_eq!(Shell::Bash.start(&style), "\\[\\e[38;5;208;44m\\]");
+        assert_eq!(
+            Shell::Bash.paint(&style, "\\u"),
+            "\\[\\e[38;5;208;44m\\]\\u\\[\\e[0m\\]"
+        );
+    }
+
+    #[test]
+    fn test_zsh() {
+        let style = Style::default()
+            .fg(Rgb::new().rgb([255, 135, 0]))
+            .bg(Ansi::DarkGray)
+            .attributes(Attributes::new().italic().underline());
+        assert_eq!(Shell::Zsh.start(&style), "%U%{\x1b[3m%}%F{#ff8700}%K{8}");
+        assert_eq!(Shell::Zsh.reset(), "%b%u%s%f%k%{\x1b[0m%}");
+    }
+
+    #[test]
+    fn test_fish() {
+        let style = Style::default()
+            .fg(Fixed::new().code(196))
+            .bg(Ansi::BrightCyan)
+            .attributes(Attributes::new().strikethrough().italic());
+        assert_eq!(
+            Shell::Fish.start(&style),
+            "set_color --italics --background=brcyan ff0000"
+        );
+        assert_eq!(Shell::Fish.paint(&Style::default(), "~"), "~");
+    }
+
+    #[test]
+    fn test_fish_unsupported_attributes() {
+        let unsupported = Attributes::new().strikethrough().blink().hidden();
+        let style = Style::default().attributes(unsupported.clone());
+        assert_eq!(Shell::Fish.start(&style), "");
+        let style = Style::default().attributes(unsupported.bold().underline());
+        assert_eq!(Shell::Fish.start(&style), "set_color --bold --underline");
+    }
+}
@@ -0,0 +1,259 @@
+/*!
+Parsing and formatting of the style strings used by Starship and similar prompt tools.
+
+A style string is a whitespace separated list of attributes and colors, where colors can be
+prefixed with `fg:` or `bg:` (unprefixed colors set the foreground):
+```rust
+use std::str::FromStr;
+use antsee::{formats::starship::StarshipStyle, Ansi};
+
+let style = StarshipStyle::from_str("bold fg:#ffffff bg:purple underline").unwrap().0;
+assert!(style.attributes.bold.0 && style.attributes.underline.0);
+assert_eq!(style.bg, Some(Ansi::Magenta.into()));
+```
+
+As in Starship, `none` or `fg:none` anywhere in the string leaves the style unstyled, while
+`bg:none` only clears the background.
+
+Colors can be given as Starship color names (`purple`, `bright-blue`), ANSI256 indices, hex values,
+or any string accepted by the [Ansi], [Fixed] and [Rgb] parsers (e.g. `css(tomato)`).
+*/
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::{
+    color::{Ansi, Color, ColorFromStrError, Fixed, Rgb},
+    style::{Attributes, Style},
+};
+
+use super::StyleFromStrError;
+
+///A [Style] which parses from and displays as a Starship style string
+#[derive(Clone, Debug, Default)]
+pub struct StarshipStyle(pub Style);
+
+impl From<Style> for StarshipStyle {
+    fn from(value: Style) -> Self {
+        Self(value)
+    }
+}
+
+impl From<StarshipStyle> for Style {
+    fn from(value: StarshipStyle) -> Self {
+        value.0
+    }
+}
+
+const ANSI_NAMES: [(Ansi, &str); 16] = [
+    (Ansi::Black, "black"),
+    (Ansi::Red, "red"),
+    (Ansi::Green, "green"),
+    (Ansi::Yellow, "yellow"),
+    (Ansi::Blue, "blue"),
+    (Ansi::Magenta, "purple"),
+    (Ansi::Cyan, "cyan"),
+    (Ansi::White, "white"),
+    (Ansi::DarkGray, "bright-black"),
+    (Ansi::BrightRed, "bright-red"),
+    (Ansi::BrightGreen, "bright-green"),
+    (Ansi::BrightYellow, "bright-yellow"),
+    (Ansi::BrightBlue, "bright-blue"),
+    (Ansi::BrightMagenta, "bright-purple"),
+    (Ansi::BrightCyan, "bright-cyan"),
+    (Ansi::LightGray, "bright-white"),
+];
+
+fn parse_color(s: &str) -> Result<Color, StyleFromStrError> {
+    if let Ok(code) = s.parse::<u8>() {
+        return Ok(Fixed::new().code(code).into());
+    }
+    let lowercase = s.to_lowercase();
+    let name = lowercase.replace("magenta", "purple");
+    if let Some((ansi, _)) = ANSI_NAMES.iter().find(|(_, n)| *n == name) {
+        return Ok((*ansi).into());
+    }
+    if let Ok(ansi) = Ansi::from_str(s) {
+        return Ok(ansi.into());
+    }
+    if let Ok(fixed) = Fixed::from_str(s) {
+        return Ok(fixed.into());
+    }
+    match Rgb::from_str(s) {
+        Ok(rgb) => Ok(rgb.into()),
+        Err(ColorFromStrError::InvalidString) => Err(StyleFromStrError::InvalidAttribute),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn format_color(color: &Color) -> Option<String> {
+    match color {
+        Color::Ansi(Ansi::Default) => None,
+        Color::Ansi(ansi) => ANSI_NAMES
+            .iter()
+            .find(|(a, _)| a == ansi)
+            .map(|(_, name)| name.to_string()),
+        Color::Fixed(fixed) => Some(fixed.0.to_string()),
+        Color::Rgb(rgb) => rgb.is_resolved().then(|| rgb.to_hex()),
+    }
+}
+
+impl FromStr for StarshipStyle {
+    type Err = StyleFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut style = Style::default();
+        let mut unstyled = false;
+        for token in s.split_whitespace() {
+            let attributes = style.attributes.clone();
+            let lowercase = token.to_lowercase();
+            let (token, background) = match lowercase.get(..3) {
+                Some("fg:") => (&token[3..], false),
+                Some("bg:") => (&token[3..], true),
+                _ => (token, false),
+            };
+            match token.to_lowercase().as_str() {
+                "none" if background => style.bg = None,
+                "none" => unstyled = true,
+                "bold" => style.attributes = attributes.bold(),
+                "italic" => style.attributes = attributes.italic(),
+                "underline" => style.attributes = attributes.underline(),
+                "dimmed" => style.attributes = attributes.dim(),
+                "inverted" => style.attributes = attributes.reverse(),
+                "blink" => style.attributes = attributes.blink(),
+                "hidden" => style.attributes = attributes.hidden(),
+                "strikethrough" => style.attributes = attributes.strikethrough(),
+                _ if background => style.bg = Some(parse_color(token)?),
+                _ => style.fg = Some(parse_color(token)?),
+            }
+        }
+        //Like Starship, `none` or `fg:none` anywhere leaves the whole style unstyled
+        if unstyled {
+            style = Style::default();
+        }
+        Ok(Self(style))
+    }
+}
+
+impl Display for StarshipStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Attributes {
+            bold,
+            dimmed,
+            italic,
+            underline,
+            blink,
+            reverse,
+            hidden,
+            strikethrough,
+            ..
+        } = &self.0.attributes;
+        let mut tokens: Vec<String> = [
+            (bold, "bold"),
+            (italic, "italic"),
+            (underline, "underline"),
+            (dimmed, "dimmed"),
+            (reverse, "inverted"),
+            (blink, "blink"),
+            (hidden, "hidden"),
+            (strikethrough, "strikethrough"),
+        ]
+        .iter()
+        .filter(|(attribute, _)| attribute.0)
+        .map(|(_, name)| name.to_string())
+        .collect();
+
+        if let Some(fg) = self.0.fg.as_ref().and_then(format_color) {
+            tokens.push(format!("fg:{}", fg));
+        }
+        if let Some(bg) = self.0.bg.as_ref().and_then(format_color) {
+            tokens.push(format!("bg:{}", bg));
+        }
+        if tokens.is_empty() {
+            return write!(f, "none");
+        }
+        write!(f, "{}", tokens.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod starship_tests {
+    use super::*;
+
+    fn parse(s: &str) -> Style {
+        StarshipStyle::from_str(s).unwrap().0
+    }
+
+    #[test]
+    fn test_parse_documented_examples() {
+        let style = parse("bold green");
+        assert!(style.attributes.bold.0);
+        assert_eq!(style.fg, Some(Ansi::Green.into()));
+
+        let style = parse("bg:#9A348E");
+        assert_eq!(
+            style.bg.unwrap().as_rgb().unwrap().get_rgb(),
+            [154, 52, 142]
+        );
+
+        let style = parse("bold italic fg:purple");
+        assert_eq!(style.attributes, Attributes::new().bold().italic());
+        assert_eq!(style.fg, Some(Ansi::Magenta.into()));
+
+        let style = parse("bright-white bold bg:red");
+        assert_eq!(style.fg, Some(Ansi::LightGray.into()));
+        assert_eq!(style.bg, Some(Ansi::Red.into()));
+
+        let style = parse("dimmed 208 inverted");
+        assert_eq!(style.fg, Some(Fixed::new().code(208).into()));
+        assert_eq!(style.attributes, Attributes::new().dim().reverse());
+    }
+
+    #[test]
+    fn test_parse_none_resets() {
+        let style = parse("bold red none underline");
+        assert_eq!(style.fg, None);
+        assert!(style.attributes.is_plain());
+
+        let style = parse("fg:none bg:blue italic");
+        assert_eq!(style.bg, None);
+        assert!(style.attributes.is_plain());
+
+        let style = parse("bold bg:blue red bg:none");
+        assert_eq!(style.fg, Some(Ansi::Red.into()));
+        assert_eq!(style.bg, None);
+        assert!(style.attributes.bold.0);
+    }
+
+    #[test]
+    fn test_parse_prefix_case() {
+        let style = parse("BG:red Fg:Blue");
+        assert_eq!(style.fg, Some(Ansi::Blue.into()));
+        assert_eq!(style.bg, Some(Ansi::Red.into()));
+    }
+
+    #[test]
+    fn test_parse_library_colors() {
+        let style = parse("fg:css(tomato) bg:xterm(Seafoam)");
+        assert_eq!(style.fg.unwrap().as_rgb().unwrap().get_rgb(), [255, 99, 71]);
+        assert!(style.bg.is_some());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            StarshipStyle::from_str("bold blinking").unwrap_err(),
+            StyleFromStrError::InvalidAttribute
+        );
+        assert!(StarshipStyle::from_str("fg:#12345").is_err());
+    }
+
+    #[test]
+    fn test_format() {
+        let style = parse("underline bold fg:#FFFFFF bg:purple");
+        assert_eq!(
+            StarshipStyle(style).to_string(),
+            "bold underline fg:#ffffff bg:purple"
+        );
+        assert_eq!(StarshipStyle::default().to_string(), "none");
+    }
+}
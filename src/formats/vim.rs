@@ -0,0 +1,386 @@
+/*!
+Export and import of Vim/Neovim highlight groups.
+
+A [Highlight] displays as a `:highlight` command and can be parsed back from one:
+```rust
+use std::str::FromStr;
+use antsee::{formats::vim::Highlight, Attributes, Rgb, Style};
+
+let style = Style::default()
+    .fg(Rgb::from_str("#ff8700").unwrap())
+    .attributes(Attributes::new().bold());
+let highlight = Highlight::new("Title", style);
+assert_eq!(
+    highlight.to_string(),
+    "highlight Title guifg=#ff8700 gui=bold ctermfg=208 cterm=bold"
+);
+```
+
+GUI colors are always written as hex values. Terminal (`cterm`) colors are derived from the style:
+[Ansi] and [Fixed] colors keep their index, and [Rgb] colors use the nearest [Fixed] color.
+
+Vim has no dim or hidden attributes, so they are left out. Blink is only written for Neovim (see
+[Highlight::neovim]), since Vim rejects it.
+*/
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::{
+    color::{
+        libraries::{ColorLibrary, CssColors},
+        Ansi, Color, ColorFromStrError, ColorValue, Fixed, Rgb,
+    },
+    style::{Attributes, Style},
+};
+
+use super::StyleFromStrError;
+
+///A named Vim highlight group
+#[derive(Clone, Debug)]
+pub struct Highlight {
+    ///Name of the highlight group (e.g. `Comment`)
+    pub group: String,
+    ///Style of the highlight group
+    pub style: Style,
+    ///Special color used for underlines and undercurls (`guisp`)
+    pub special: Option<Color>,
+    ///Whether the command is written for Neovim, which also accepts `blink`
+    pub neovim: bool,
+}
+
+impl Highlight {
+    ///Create a new highlight group from a style
+    pub fn new(group: &str, style: Style) -> Self {
+        Self {
+            group: group.to_string(),
+            style,
+            special: None,
+            neovim: false,
+        }
+    }
+
+    ///Set the special color of the highlight group
+    pub fn special<C: ColorValue>(mut self, special: C) -> Self {
+        self.special = Some(special.into());
+        self
+    }
+
+    ///Write the command for Neovim, which also accepts the blink attribute
+    pub fn neovim(mut self) -> Self {
+        self.neovim = true;
+        self
+    }
+}
+
+fn gui_color(color: &Color) -> String {
+    match color.to_rgb() {
+        Some(rgb) => rgb.to_hex(),
+        None => "NONE".to_string(),
+    }
+}
+
+fn cterm_color(color: &Color) -> String {
+    let index = match color {
+        Color::Ansi(ansi) => ansi.index(),
+        Color::Fixed(fixed) => Some(fixed.0),
+        Color::Rgb(rgb) => Some(rgb.to_fixed().0),
+    };
+    match index {
+        Some(index) => index.to_string(),
+        None => "NONE".to_string(),
+    }
+}
+
+fn attribute_list(attributes: &Attributes, neovim: bool) -> String {
+    let names = [
+        (attributes.bold.0, "bold"),
+        (attributes.italic.0, "italic"),
+        (attributes.underline.0, "underline"),
+        (attributes.reverse.0, "reverse"),
+        (attributes.strikethrough.0, "strikethrough"),
+        (attributes.blink.0 && neovim, "blink"),
+    ];
+    let list: Vec<&str> = names
+        .iter()
+        .filter(|(on, _)| *on)
+        .map(|(_, name)| *name)
+        .collect();
+    if list.is_empty() {
+        return "NONE".to_string();
+    }
+    list.join(",")
+}
+
+fn parse_attributes(list: &str) -> Result<Attributes, StyleFromStrError> {
+    let mut attributes = Attributes::new();
+    for name in list.split(',') {
+        match name.to_lowercase().as_str() {
+            "none" | "nocombine" => {}
+            "bold" => attributes = attributes.bold(),
+            "italic" => attributes = attributes.italic(),
+            "underline" | "undercurl" | "underdouble" | "underdotted" | "underdashed" => {
+                attributes = attributes.underline()
+            }
+            "reverse" | "inverse" | "standout" => attributes = attributes.reverse(),
+            "strikethrough" => attributes = attributes.strikethrough(),
+            "blink" => attributes = attributes.blink(),
+            _ => return Err(StyleFromStrError::InvalidAttribute),
+        }
+    }
+    Ok(attributes)
+}
+
+fn parse_gui_color(value: &str) -> Result<Option<Color>, StyleFromStrError> {
+    let value = value.trim_matches('\'');
+    match value.to_lowercase().as_str() {
+        "none" => return Ok(None),
+        "fg" | "bg" | "foreground" | "background" => return Ok(Some(Ansi::Default.into())),
+        _ => {}
+    }
+    if value.starts_with('#') {
+        return Ok(Some(Rgb::from_str(value)?.into()));
+    }
+    let name = value.to_lowercase().replace(' ', "");
+    match CssColors::get_name(&name) {
+        Some(color) => Ok(Some(Rgb::from(color).into())),
+        None => Err(StyleFromStrError::InvalidColor(
+            ColorFromStrError::InvalidName,
+        )),
+    }
+}
+
+fn parse_cterm_color(value: &str) -> Result<Option<Color>, StyleFromStrError> {
+    if let Ok(index) = value.parse::<u8>() {
+        if let Some(ansi) = Ansi::from_index(index) {
+            return Ok(Some(ansi.into()));
+        }
+        return Ok(Some(Fixed::new().code(index).into()));
+    }
+    let index = match value.to_lowercase().as_str() {
+        "none" => return Ok(None),
+        "fg" | "bg" => return Ok(Some(Ansi::Default.into())),
+        "black" => 0,
+        "darkred" => 1,
+        "darkgreen" => 2,
+        "brown" | "darkyellow" => 3,
+        "darkblue" => 4,
+        "darkmagenta" => 5,
+        "darkcyan" => 6,
+        "lightgray" | "lightgrey" | "gray" | "grey" => 7,
+        "darkgray" | "darkgrey" => 8,
+        "red" | "lightred" => 9,
+        "green" | "lightgreen" => 10,
+        "yellow" | "lightyellow" => 11,
+        "blue" | "lightblue" => 12,
+        "magenta" | "lightmagenta" => 13,
+        "cyan" | "lightcyan" => 14,
+        "white" => 15,
+        _ => {
+            return Err(StyleFromStrError::InvalidColor(
+                ColorFromStrError::InvalidName,
+            ))
+        }
+    };
+    Ok(Ansi::from_index(index).map(Color::from))
+}
+
+impl Display for Highlight {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "highlight {}", self.group)?;
+        //Unresolved colors are left out, so the group keeps its current color
+        let (fg, bg) = (
+            self.style.fg.as_ref().filter(|fg| fg.is_resolved()),
+            self.style.bg.as_ref().filter(|bg| bg.is_resolved()),
+        );
+        if let Some(fg) = fg {
+            write!(f, " guifg={}", gui_color(fg))?;
+        }
+        if let Some(bg) = bg {
+            write!(f, " guibg={}", gui_color(bg))?;
+        }
+        if let Some(special) = self.special.as_ref().filter(|sp| sp.is_resolved()) {
+            write!(f, " guisp={}", gui_color(special))?;
+        }
+        write!(
+            f,
+            " gui={}",
+            attribute_list(&self.style.attributes, self.neovim)
+        )?;
+        if let Some(fg) = fg {
+            write!(f, " ctermfg={}", cterm_color(fg))?;
+        }
+        if let Some(bg) = bg {
+            write!(f, " ctermbg={}", cterm_color(bg))?;
+        }
+        write!(
+            f,
+            " cterm={}",
+            attribute_list(&self.style.attributes, self.neovim)
+        )
+    }
+}
+
+///Split a command into words on whitespace, keeping quoted values such as `guifg='dark green'`
+///together
+fn split_words(s: &str) -> Result<Vec<&str>, StyleFromStrError> {
+    let mut words = Vec::new();
+    let mut start = None;
+    let mut quoted = false;
+    for (i, c) in s.char_indices() {
+        match c {
+            '\'' => quoted = !quoted,
+            c if c.is_whitespace() && !quoted => {
+                if let Some(start) = start.take() {
+                    words.push(&s[start..i]);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        start.get_or_insert(i);
+    }
+    if quoted {
+        return Err(StyleFromStrError::InvalidSyntax);
+    }
+    if let Some(start) = start {
+        words.push(&s[start..]);
+    }
+    Ok(words)
+}
+
+impl FromStr for Highlight {
+    type Err = StyleFromStrError;
+
+    ///Parse a `:highlight` command. GUI values take precedence over their `cterm` equivalents.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = split_words(s)?.into_iter();
+        let command = words.next().unwrap_or_default().trim_start_matches(':');
+        if !matches!(command, "hi" | "hi!" | "highlight" | "highlight!") {
+            return Err(StyleFromStrError::InvalidSyntax);
+        }
+        let mut group = words.next().ok_or(StyleFromStrError::InvalidSyntax)?;
+        if matches!(group, "def" | "default") {
+            group = words.next().ok_or(StyleFromStrError::InvalidSyntax)?;
+        }
+        if matches!(group, "clear" | "link") {
+            return Err(StyleFromStrError::InvalidSyntax);
+        }
+
+        let (mut gui_fg, mut gui_bg, mut gui_attributes) = (None, None, None);
+        let (mut cterm_fg, mut cterm_bg, mut cterm_attributes) = (None, None, None);
+        let mut special = None;
+        for word in words {
+            let (key, value) = word
+                .split_once('=')
+                .ok_or(StyleFromStrError::InvalidSyntax)?;
+            match key {
+                "guifg" => gui_fg = Some(parse_gui_color(value)?),
+                "guibg" => gui_bg = Some(parse_gui_color(value)?),
+                "guisp" => special = parse_gui_color(value)?,
+                "gui" => gui_attributes = Some(parse_attributes(value)?),
+                "ctermfg" => cterm_fg = Some(parse_cterm_color(value)?),
+                "ctermbg" => cterm_bg = Some(parse_cterm_color(value)?),
+                "cterm" => cterm_attributes = Some(parse_attributes(value)?),
+                "term" | "ctermul" | "start" | "stop" | "font" | "blend" => {}
+                _ => return Err(StyleFromStrError::InvalidSyntax),
+            }
+        }
+
+        let attributes: Attributes = gui_attributes.or(cterm_attributes).unwrap_or_default();
+        Ok(Self {
+            group: group.to_string(),
+            //Only Neovim accepts blink, so a command using it is written back for Neovim
+            neovim: attributes.blink.0,
+            style: Style {
+                fg: gui_fg.or(cterm_fg).flatten(),
+                bg: gui_bg.or(cterm_bg).flatten(),
+                attributes,
+            },
+            special,
+        })
+    }
+}
+
+#[cfg(test)]
+mod vim_tests {
+    use super::*;
+
+    #[test]
+    fn test_highlight_ansi() {
+        let style = Style::default().fg(Ansi::Red).bg(Ansi::Default);
+        let highlight = Highlight::new("Error", style);
+        assert_eq!(
+            highlight.to_string(),
+            "highlight Error guifg=#800000 guibg=NONE gui=NONE ctermfg=1 ctermbg=NONE cterm=NONE"
+        );
+    }
+
+    #[test]
+    fn test_highlight_rgb_cterm() {
+        let style = Style::default().bg(Rgb::from_str("#1c1c1c").unwrap());
+        let highlight = Highlight::new("Normal", style).special(Rgb::new().rgb([255, 0, 0]));
+        assert_eq!(
+            highlight.to_string(),
+            "highlight Normal guibg=#1c1c1c guisp=#ff0000 gui=NONE ctermbg=234 cterm=NONE"
+        );
+    }
+
+    #[test]
+    fn test_parse_highlight() {
+        let highlight =
+            Highlight::from_str("hi! def Comment guifg=#5f875f ctermfg=65 gui=italic,bold")
+                .unwrap();
+        assert_eq!(highlight.group, "Comment");
+        assert_eq!(
+            highlight.style.fg.unwrap().as_rgb().unwrap().get_rgb(),
+            [95, 135, 95]
+        );
+        assert_eq!(
+            highlight.style.attributes,
+            Attributes::new().bold().italic()
+        );
+    }
+
+    #[test]
+    fn test_parse_cterm_only() {
+        let highlight = Highlight::from_str(
+            "highlight default Visual ctermfg=DarkRed ctermbg=236 cterm=reverse",
+        )
+        .unwrap();
+        assert_eq!(highlight.style.fg, Some(Ansi::Red.into()));
+        assert_eq!(highlight.style.bg, Some(Fixed::new().code(236).into()));
+        assert!(highlight.style.attributes.reverse.0);
+    }
+
+    #[test]
+    fn test_parse_quoted_color() {
+        let highlight =
+            Highlight::from_str("hi Title guifg='dark green' guibg='Light Goldenrod Yellow'")
+                .unwrap();
+        assert_eq!(
+            highlight.style.fg,
+            Some(Rgb::from(CssColors::DarkGreen).into())
+        );
+        assert_eq!(
+            highlight.style.bg,
+            Some(Rgb::from(CssColors::LightGoldenrodYellow).into())
+        );
+        assert!(Highlight::from_str("hi Title guifg='dark green").is_err());
+    }
+
+    #[test]
+    fn test_highlight_other_attributes() {
+        let style = Style::default().attributes(Attributes::new().dim().blink().hidden());
+        let highlight = Highlight::new("Ghost", style.clone());
+        assert_eq!(highlight.to_string(), "highlight Ghost gui=NONE cterm=NONE");
+
+        let highlight = Highlight::new("Ghost", style).neovim();
+        assert_eq!(
+            highlight.to_string(),
+            "highlight Ghost gui=blink cterm=blink"
+        );
+        let parsed = Highlight::from_str(&highlight.to_string()).unwrap();
+        assert!(parsed.neovim);
+        assert_eq!(parsed.style.attributes, Attributes::new().blink());
+    }
+}
@@ -0,0 +1,277 @@
+/*!
+Palettes generated from a single color.
+
+- [Rgb::harmony] rotates the hue of a color to get a [Harmony], such as its complement.
+- [Rgb::tints] and [Rgb::shades] mix a color with white or black for accent ramps.
+- [Rgb::categorical] picks colors which are as far apart as possible, for telling series of data
+  apart. The colors can be limited to the [Fixed] or [Ansi] palettes with a [Gamut].
+
+Generated colors can be used to fill in a [Theme](crate::Theme):
+```rust
+use antsee::{generate::Gamut, Rgb, Style, Theme};
+
+let brand = Rgb::new().hex("#ff5f00").unwrap();
+let mut theme = Theme::new();
+for (i, color) in brand.categorical(4, Gamut::Fixed).into_iter().enumerate() {
+    let style = Style {
+        fg: Some(color),
+        ..Default::default()
+    };
+    theme.set(&format!("series.{}", i), style);
+}
+assert_eq!(theme.roles().count(), 4);
+```
+*/
+
+use crate::{
+    color::{
+        libraries::{ColorLibrary, XtermColors},
+        space::Oklch,
+        Ansi, Color, DistanceMetric, Fixed, Rgb,
+    },
+    contrast::AnsiPalette,
+};
+
+///A set of colors with hues at fixed angles to each other
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum Harmony {
+    ///The color and the color opposite it
+    Complementary,
+    ///The color and the two colors either side of its complement
+    SplitComplementary,
+    ///The color and its neighbours 30° either side
+    Analogous,
+    ///Three colors 120° apart
+    Triadic,
+    ///Four colors 90° apart
+    Tetradic,
+}
+
+impl Harmony {
+    ///The hue rotation of each color of the harmony in degrees, starting with 0
+    pub fn offsets(&self) -> &'static [f64] {
+        match self {
+            Self::Complementary => &[0.0, 180.0],
+            Self::SplitComplementary => &[0.0, 150.0, 210.0],
+            Self::Analogous => &[0.0, -30.0, 30.0],
+            Self::Triadic => &[0.0, 120.0, 240.0],
+            Self::Tetradic => &[0.0, 90.0, 180.0, 270.0],
+        }
+    }
+}
+
+///The palette [Rgb::categorical] picks colors from
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Gamut {
+    ///Any RGB color
+    #[default]
+    Rgb,
+    ///The ANSI256 colors from 16 to 255, which unlike colors 0-15 are the same in most terminals
+    Fixed,
+    ///The 16 [Ansi] colors, using the xterm palette to measure distances
+    Ansi,
+}
+
+///Returns whether a color is colorful enough and in the right lightness range to tell series apart
+fn is_distinct(rgb: [u8; 3]) -> bool {
+    let oklch = Oklch::from_rgb(rgb);
+    oklch.c >= 0.06 && (0.4..=0.95).contains(&oklch.l)
+}
+
+impl Gamut {
+    fn candidates(&self) -> Vec<(Color, [u8; 3])> {
+        let candidates: Vec<(Color, [u8; 3])> = match self {
+            Self::Rgb => {
+                let levels: Vec<u8> = (0..12).map(|i| (i * 255 / 11) as u8).collect();
+                let mut colors = Vec::new();
+                for r in &levels {
+                    for g in &levels {
+                        for b in &levels {
+                            let rgb = [*r, *g, *b];
+                            colors.push((Rgb::new().rgb(rgb).into(), rgb));
+                        }
+                    }
+                }
+                colors
+            }
+            Self::Fixed => (16..=255)
+                .map(|code| {
+                    let rgb = XtermColors::get_ansi256(code).rgb();
+                    (Fixed::new().code(code).into(), rgb)
+                })
+                .collect(),
+            Self::Ansi => {
+                let palette = AnsiPalette::default();
+                (0..16)
+                    .filter_map(Ansi::from_index)
+                    .map(|ansi| (ansi.into(), palette.rgb(&ansi.into(), [0; 3]).get_rgb()))
+                    .collect()
+            }
+        };
+        candidates
+            .into_iter()
+            .filter(|(_, rgb)| is_distinct(*rgb))
+            .collect()
+    }
+}
+
+impl Rgb {
+    ///Get the colors of a [Harmony], starting with this color. The other colors have inactive
+    ///sources.
+    pub fn harmony(&self, harmony: Harmony) -> Vec<Rgb> {
+        harmony
+            .offsets()
+            .iter()
+            .map(|offset| match *offset == 0.0 {
+                true => self.clone(),
+                false => self.rotate_hue(*offset),
+            })
+            .collect()
+    }
+
+    ///Get `steps` colors mixed with more and more white, from closest to this color to closest to
+    ///white. Neither this color nor white are included.
+    pub fn tints(&self, steps: usize) -> Vec<Rgb> {
+        self.ramp(&Rgb::new().rgb([255; 3]), steps)
+    }
+
+    ///Get `steps` colors mixed with more and more black, from closest to this color to closest to
+    ///black. Neither this color nor black are included.
+    pub fn shades(&self, steps: usize) -> Vec<Rgb> {
+        self.ramp(&Rgb::new().rgb([0; 3]), steps)
+    }
+
+    fn ramp(&self, target: &Rgb, steps: usize) -> Vec<Rgb> {
+        (1..=steps)
+            .map(|i| self.mix(target, i as f64 / (steps + 1) as f64))
+            .collect()
+    }
+
+    ///Pick up to `count` colors from a [Gamut] which are as far apart as possible with the default
+    ///[DistanceMetric], starting with this color (or the closest color to it in the gamut). Grays
+    ///and very dark colors are skipped, so fewer colors are returned if the gamut runs out.
+    pub fn categorical(&self, count: usize, gamut: Gamut) -> Vec<Color> {
+        self.categorical_with(count, gamut, DistanceMetric::default())
+    }
+
+    ///Pick up to `count` colors from a [Gamut] which are as far apart as possible with a
+    ///[DistanceMetric]. See [Rgb::categorical].
+    pub fn categorical_with(
+        &self,
+        count: usize,
+        gamut: Gamut,
+        metric: DistanceMetric,
+    ) -> Vec<Color> {
+        let mut candidates = gamut.candidates();
+        let first = match gamut {
+            Gamut::Rgb => (self.clone().into(), self.get_rgb()),
+            _ => {
+                let nearest = metric.nearest(
+                    self.get_rgb(),
+                    candidates.iter().enumerate().map(|(i, (_, rgb))| (i, *rgb)),
+                );
+                match nearest {
+                    Some((i, _)) => candidates.remove(i),
+                    None => return Vec::new(),
+                }
+            }
+        };
+        if count == 0 {
+            return Vec::new();
+        }
+        //The distance from each candidate to the closest color picked so far
+        let mut distances: Vec<f64> = candidates
+            .iter()
+            .map(|(_, rgb)| metric.between(first.1, *rgb))
+            .collect();
+        let mut picked = vec![first.0];
+        while picked.len() < count && !candidates.is_empty() {
+            let Some((i, _)) = distances
+                .iter()
+                .enumerate()
+                .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            else {
+                break;
+            };
+            let (color, rgb) = candidates.swap_remove(i);
+            distances.swap_remove(i);
+            for (distance, (_, candidate)) in distances.iter_mut().zip(&candidates) {
+                *distance = distance.min(metric.between(rgb, *candidate));
+            }
+            picked.push(color);
+        }
+        picked
+    }
+}
+
+#[cfg(test)]
+mod generate_tests {
+    use super::*;
+
+    #[test]
+    fn test_harmony() {
+        let red = Rgb::new().hex("#ff0000").unwrap();
+        let triadic = red.harmony(Harmony::Triadic);
+        assert_eq!(triadic.len(), 3);
+        assert_eq!(triadic[0], red);
+        let hue = |rgb: &Rgb| Oklch::from_rgb(rgb.get_rgb()).h;
+        let difference = (hue(&triadic[1]) - hue(&red)).rem_euclid(360.0);
+        assert!((difference - 120.0).abs() < 2.0, "{}", difference);
+        assert_eq!(red.harmony(Harmony::Tetradic).len(), 4);
+    }
+
+    #[test]
+    fn test_tints_and_shades() {
+        let color = Rgb::new().rgb([200, 50, 50]);
+        let lightness = |rgb: &Rgb| Oklch::from_rgb(rgb.get_rgb()).l;
+        let tints = color.tints(4);
+        assert_eq!(tints.len(), 4);
+        assert!(tints
+            .windows(2)
+            .all(|w| lightness(&w[0]) < lightness(&w[1])));
+        assert!(lightness(&tints[0]) > lightness(&color));
+        assert!(tints[3].get_rgb() != [255; 3]);
+        let shades = color.shades(3);
+        assert!(shades
+            .windows(2)
+            .all(|w| lightness(&w[0]) > lightness(&w[1])));
+        assert!(color.shades(0).is_empty());
+    }
+
+    #[test]
+    fn test_categorical() {
+        let brand = Rgb::new().hex("#ff5f00").unwrap();
+        let colors = brand.categorical(6, Gamut::Rgb);
+        assert_eq!(colors.len(), 6);
+        assert_eq!(colors[0], Color::Rgb(brand.clone()));
+        let metric = DistanceMetric::default();
+        for (i, a) in colors.iter().enumerate() {
+            for b in &colors[i + 1..] {
+                assert!(a.distance(b, metric).unwrap() > 15.0);
+            }
+        }
+
+        let fixed = brand.categorical(8, Gamut::Fixed);
+        assert_eq!(fixed.len(), 8);
+        assert_eq!(fixed[0], Color::Fixed(Fixed::new().code(202)));
+        assert!(fixed
+            .iter()
+            .all(|c| c.as_fixed().is_some_and(|f| f.0 >= 16)));
+
+        let ansi = brand.categorical(32, Gamut::Ansi);
+        assert!(ansi.len() < 16 && ansi.len() > 6);
+        assert!(!ansi.contains(&Color::Ansi(Ansi::Black)));
+        assert!(!ansi.contains(&Color::Ansi(Ansi::LightGray)));
+        assert!(brand.categorical(0, Gamut::Ansi).is_empty());
+    }
+}
@@ -0,0 +1,412 @@
+/*!
+Gradients between several colors.
+
+A [Gradient] is a list of [Color] stops. Stops without a position are spread evenly between their
+neighbours, so a gradient can be written in a configuration file as a list of colors:
+```rust
+use antsee::{gradient::Gradient, Ansi, Rgb};
+
+let gradient = Gradient::new()
+    .stop(Rgb::new().hex("#ff0000").unwrap())
+    .stop(Ansi::Blue);
+let middle = gradient.sample(0.5).unwrap();
+let steps = gradient.steps(5);
+assert_eq!(steps.len(), 5);
+assert_eq!(steps[2], middle);
+```
+With the `serde` feature, a gradient is deserialized from either a list of colors
+(`["#ff0000", "css(gold)", "xterm(Seafoam)"]`) or a table with `stops` and an `interpolation`.
+Stops are either a color or a table with a `color` and a `position`.
+*/
+
+use crate::{
+    color::{
+        space::{from_linear, to_linear},
+        Color, Rgb,
+    },
+    formats::sgr,
+    style::Style,
+};
+
+///The color space colors are interpolated in
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Interpolation {
+    ///Interpolate the sRGB channels directly, as most image editors and CSS do by default
+    Srgb,
+    ///Interpolate in linear light. Mixes of saturated colors are brighter than in sRGB.
+    Linear,
+    ///Interpolate in Oklab, which keeps the lightness of mixes even
+    #[default]
+    Oklab,
+}
+
+impl Interpolation {
+    ///Mix two colors. `amount` is the share of `b`.
+    fn mix(&self, a: &Rgb, b: &Rgb, amount: f64) -> Rgb {
+        let (x, y) = (a.get_rgb(), b.get_rgb());
+        match self {
+            Self::Srgb => Rgb::new().rgb(
+                [0, 1, 2]
+                    .map(|i| (x[i] as f64 + (y[i] as f64 - x[i] as f64) * amount).round() as u8),
+            ),
+            Self::Linear => Rgb::new().rgb([0, 1, 2].map(|i| {
+                let (x, y) = (to_linear(x[i]), to_linear(y[i]));
+                from_linear(x + (y - x) * amount)
+            })),
+            Self::Oklab => a.mix(b, amount),
+        }
+    }
+}
+
+///A color in a [Gradient]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GradientStop {
+    ///The color of the stop
+    pub color: Color,
+    ///The position of the stop from 0 to 1. [None] spreads the stop evenly between its
+    ///neighbours.
+    pub position: Option<f64>,
+}
+
+///A gradient between [Color] stops. See the [module documentation](self).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Gradient {
+    stops: Vec<GradientStop>,
+    interpolation: Interpolation,
+}
+
+impl Gradient {
+    ///Create a gradient with no stops, interpolated in Oklab
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///Add a stop spread evenly between its neighbours
+    pub fn stop<C: Into<Color>>(mut self, color: C) -> Self {
+        self.push(color.into(), None);
+        self
+    }
+
+    ///Add a stop at a position from 0 to 1
+    pub fn stop_at<C: Into<Color>>(mut self, color: C, position: f64) -> Self {
+        self.push(color.into(), Some(position));
+        self
+    }
+
+    ///Add a stop to the end of the gradient
+    pub fn push(&mut self, color: Color, position: Option<f64>) {
+        self.stops.push(GradientStop { color, position });
+    }
+
+    ///Set the color space the gradient is interpolated in
+    pub fn interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    ///Set the color space the gradient is interpolated in
+    pub fn set_interpolation(&mut self, interpolation: Interpolation) {
+        self.interpolation = interpolation;
+    }
+
+    ///Get the color space the gradient is interpolated in
+    pub fn get_interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    ///Get the stops of the gradient
+    pub fn stops(&self) -> &[GradientStop] {
+        &self.stops
+    }
+
+    ///Get the position of every stop. The first and last stops default to 0 and 1, stops without
+    ///a position are spread evenly between their neighbours, and a stop before the stop preceding
+    ///it is moved to that stop.
+    pub fn positions(&self) -> Vec<f64> {
+        let count = self.stops.len();
+        let mut positions: Vec<Option<f64>> = self.stops.iter().map(|s| s.position).collect();
+        if let Some(first) = positions.first_mut() {
+            first.get_or_insert(0.0);
+        }
+        if count > 1 {
+            positions[count - 1].get_or_insert(1.0);
+        }
+        let mut previous = f64::MIN;
+        for position in positions.iter_mut().flatten() {
+            *position = position.max(previous);
+            previous = *position;
+        }
+        let mut i = 0;
+        while i < count {
+            if positions[i].is_some() {
+                i += 1;
+                continue;
+            }
+            let start = i - 1;
+            let end = (i..count)
+                .find(|j| positions[*j].is_some())
+                .unwrap_or(count - 1);
+            let (a, b) = (
+                positions[start].unwrap_or_default(),
+                positions[end].unwrap_or(1.0),
+            );
+            for (j, position) in positions.iter_mut().enumerate().take(end).skip(i) {
+                let fraction = (j - start) as f64 / (end - start) as f64;
+                *position = Some(a + (b - a) * fraction);
+            }
+            i = end;
+        }
+        positions.into_iter().flatten().collect()
+    }
+
+    ///Get the color at a position from 0 to 1, using the standard xterm palette for [Ansi] and
+    ///[Fixed](crate::Fixed) stops. The result has an inactive source.
+    ///
+    ///Returns [None] if the gradient has no stops, or a stop is
+    ///[Ansi::Default](crate::Ansi::Default).
+    ///
+    ///[Ansi]: crate::Ansi
+    pub fn sample(&self, t: f64) -> Option<Rgb> {
+        let colors = self
+            .stops
+            .iter()
+            .map(|stop| stop.color.to_rgb())
+            .collect::<Option<Vec<Rgb>>>()?;
+        let positions = self.positions();
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let last = colors.len().checked_sub(1)?;
+        let derived = |rgb: &Rgb| Rgb::new().rgb(rgb.get_rgb());
+        if t >= positions[last] {
+            return Some(derived(&colors[last]));
+        }
+        //The last segment starting at or before t, so the later color wins at a hard stop
+        let Some(i) = (0..last).rev().find(|i| positions[*i] <= t) else {
+            return Some(derived(&colors[0]));
+        };
+        let (start, end) = (positions[i], positions[i + 1]);
+        if end <= start {
+            return Some(derived(&colors[i + 1]));
+        }
+        if t == start {
+            return Some(derived(&colors[i]));
+        }
+        let amount = (t - start) / (end - start);
+        Some(self.interpolation.mix(&colors[i], &colors[i + 1], amount))
+    }
+
+    ///Sample the gradient at `count` evenly spaced positions, including both ends. Returns an
+    ///empty list if the gradient can't be sampled.
+    pub fn steps(&self, count: usize) -> Vec<Rgb> {
+        let positions = (0..count).map(|i| match count {
+            1 => 0.0,
+            _ => i as f64 / (count - 1) as f64,
+        });
+        positions
+            .map(|t| self.sample(t))
+            .collect::<Option<Vec<Rgb>>>()
+            .unwrap_or_default()
+    }
+
+    ///Paint text with the gradient, giving each character the foreground color of its position
+    ///with [sgr] escape sequences. The background and attributes are taken from `style`. Text is
+    ///painted with just `style` if the gradient can't be sampled.
+    pub fn paint(&self, style: &Style, text: &str) -> String {
+        let colors = self.steps(text.chars().count());
+        if colors.is_empty() {
+            return sgr::paint(style, text);
+        }
+        let mut painted = String::new();
+        for (c, color) in text.chars().zip(colors) {
+            let style = style.clone().fg(color);
+            painted.push_str(&sgr::escape(&style));
+            painted.push(c);
+        }
+        painted.push_str(sgr::RESET);
+        painted
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+enum StopEntry {
+    Color(Color),
+    Positioned { color: Color, position: f64 },
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+enum GradientEntry {
+    Stops(Vec<StopEntry>),
+    Table {
+        stops: Vec<StopEntry>,
+        #[serde(default)]
+        interpolation: Interpolation,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Gradient {
+    ///Serializes as a list of colors if no stop has a position and the interpolation is the
+    ///default, and as a table otherwise
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let stops = self
+            .stops
+            .iter()
+            .map(|stop| match stop.position {
+                Some(position) => StopEntry::Positioned {
+                    color: stop.color.clone(),
+                    position,
+                },
+                None => StopEntry::Color(stop.color.clone()),
+            })
+            .collect();
+        let plain = self.interpolation == Interpolation::default()
+            && self.stops.iter().all(|stop| stop.position.is_none());
+        let entry = match plain {
+            true => GradientEntry::Stops(stops),
+            false => GradientEntry::Table {
+                stops,
+                interpolation: self.interpolation,
+            },
+        };
+        entry.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Gradient {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (stops, interpolation) = match GradientEntry::deserialize(deserializer)? {
+            GradientEntry::Stops(stops) => (stops, Interpolation::default()),
+            GradientEntry::Table {
+                stops,
+                interpolation,
+            } => (stops, interpolation),
+        };
+        let stops = stops
+            .into_iter()
+            .map(|stop| match stop {
+                StopEntry::Color(color) => GradientStop {
+                    color,
+                    position: None,
+                },
+                StopEntry::Positioned { color, position } => GradientStop {
+                    color,
+                    position: Some(position),
+                },
+            })
+            .collect();
+        Ok(Self {
+            stops,
+            interpolation,
+        })
+    }
+}
+
+#[cfg(test)]
+mod gradient_tests {
+    use super::*;
+    use crate::color::Ansi;
+
+    fn rgb(value: [u8; 3]) -> Rgb {
+        Rgb::new().rgb(value)
+    }
+
+    #[test]
+    fn test_positions() {
+        let gradient = Gradient::new()
+            .stop(Ansi::Red)
+            .stop(Ansi::Green)
+            .stop_at(Ansi::Blue, 0.8)
+            .stop(Ansi::White)
+            .stop_at(Ansi::Black, 0.5);
+        let positions = gradient.positions();
+        assert_eq!(positions.len(), 5);
+        assert_eq!(positions[0], 0.0);
+        assert!((positions[1] - 0.4).abs() < 1e-9);
+        assert_eq!(positions[2], 0.8);
+        assert!((positions[3] - 0.8).abs() < 1e-9);
+        assert_eq!(positions[4], 0.8);
+        assert_eq!(Gradient::new().stop(Ansi::Red).positions(), [0.0]);
+    }
+
+    #[test]
+    fn test_sample() {
+        let black_white = |interpolation| {
+            Gradient::new()
+                .stop(rgb([0; 3]))
+                .stop(rgb([255; 3]))
+                .interpolation(interpolation)
+        };
+        assert_eq!(
+            black_white(Interpolation::Srgb).sample(0.5),
+            Some(rgb([128; 3]))
+        );
+        assert_eq!(
+            black_white(Interpolation::Linear).sample(0.5),
+            Some(rgb([188; 3]))
+        );
+        let oklab = black_white(Interpolation::Oklab).sample(0.5).unwrap();
+        assert!(oklab.get_rgb()[0] > 90 && oklab.get_rgb()[0] < 110);
+        assert_eq!(
+            black_white(Interpolation::Srgb).sample(-1.0),
+            Some(rgb([0; 3]))
+        );
+
+        let hard = Gradient::new()
+            .stop_at(rgb([255, 0, 0]), 0.5)
+            .stop_at(rgb([0, 0, 255]), 0.5);
+        assert_eq!(hard.sample(0.49), Some(rgb([255, 0, 0])));
+        assert_eq!(hard.sample(0.5), Some(rgb([0, 0, 255])));
+
+        assert_eq!(Gradient::new().sample(0.5), None);
+        assert_eq!(Gradient::new().stop(Ansi::Default).sample(0.5), None);
+        assert_eq!(Gradient::new().stop(rgb([1, 2, 3])).steps(3).len(), 3);
+    }
+
+    #[test]
+    fn test_paint() {
+        let gradient = Gradient::new()
+            .stop(rgb([255, 0, 0]))
+            .stop(rgb([0, 0, 255]))
+            .interpolation(Interpolation::Srgb);
+        assert_eq!(
+            gradient.paint(&Style::default(), "ab"),
+            "\x1b[38;2;255;0;0ma\x1b[38;2;0;0;255mb\x1b[0m"
+        );
+        assert_eq!(Gradient::new().paint(&Style::default(), "ab"), "ab");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_gradient() {
+        let gradient: Gradient =
+            serde_json::from_str(r##"["#ff0000", "css(gold)", "xterm(Seafoam)"]"##).unwrap();
+        assert_eq!(gradient.stops().len(), 3);
+        assert_eq!(gradient.sample(0.5).unwrap().get_rgb(), [255, 215, 0]);
+        assert_eq!(
+            serde_json::to_string(&gradient).unwrap(),
+            r##"["#ff0000","css(gold)","xterm(Seafoam)"]"##
+        );
+
+        let table =
+            r##"{"stops":["#000000",{"color":"#ffffff","position":0.5}],"interpolation":"srgb"}"##;
+        let gradient: Gradient = serde_json::from_str(table).unwrap();
+        assert_eq!(gradient.get_interpolation(), Interpolation::Srgb);
+        assert_eq!(gradient.sample(0.25), Some(rgb([128; 3])));
+        assert_eq!(serde_json::to_string(&gradient).unwrap(), table);
+    }
+}
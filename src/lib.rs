@@ -30,6 +30,12 @@ let css_color = antsee::Rgb::from_str("css(red)").unwrap();
 let xterm_color = antsee::Fixed::from_str("xterm(Seafoam)").unwrap();
 ```
 
+Libraries for popular palettes such as `tailwind(blue-500)` and `catppuccin(mocha.mauve)` are
+available behind cargo features, listed in [color::libraries].
+
+Libraries of your own, such as brand colors referenced as `brand(primary)`, can be added at
+runtime with the [ColorRegistry](color::registry::ColorRegistry).
+
 ## Serde
   While `serde` is an optional feature, most of the useful functionality of this crate
 lies in its custom implementations of `Serialize` and `Deserialize`. These custom implementations
@@ -55,6 +61,31 @@ pub mod color;
 /** style holds the [Style] type and the [Attributes] type. */
 pub mod style;
 
-pub use color::libraries::{CssColors, XtermColors};
+/** theme holds the [Theme] type, which maps role names to [Style]s */
+pub mod theme;
+
+/** formats converts [Style] to and from the color configuration formats of other tools */
+pub mod formats;
+
+/** contrast measures the readability of text colors on background colors */
+pub mod contrast;
+
+/** vision simulates color vision deficiencies */
+pub mod vision;
+
+/** lint checks [Style]s and [Theme]s for common mistakes */
+pub mod lint;
+
+/** gradient interpolates between several [Color]s */
+pub mod gradient;
+
+/** generate derives palettes such as harmonies and categorical colors from a single color */
+pub mod generate;
+
+/** assign gives arbitrary keys, such as usernames, consistent colors */
+pub mod assign;
+
+pub use color::libraries::{CssColors, Xterm256Colors, XtermColors};
 pub use color::{Ansi, Color, Fixed, Rgb};
 pub use style::{Attributes, Style};
+pub use theme::{Palette, Theme};
@@ -0,0 +1,317 @@
+/*!
+Checks for common mistakes in [Style]s and [Theme]s.
+
+Each problem is reported as a [Diagnostic] holding the key of the role it was found in:
+```rust
+use antsee::{lint::{LintKind, LintOptions}, Ansi, Attributes, Style, Theme};
+
+let theme = Theme::new()
+    .role("title", Style::default().fg(Ansi::Blue).bg(Ansi::Blue))
+    .role("muted", Style::default().attributes(Attributes::new().bold().dim()));
+let diagnostics = theme.lint(&LintOptions::default());
+assert!(diagnostics
+    .iter()
+    .any(|d| d.key == "title" && d.kind == LintKind::SameColors));
+assert!(diagnostics
+    .iter()
+    .any(|d| d.key == "muted" && d.kind == LintKind::BoldAndDimmed));
+```
+*/
+
+use std::fmt::Display;
+
+use crate::{
+    color::{Ansi, Color, DistanceMetric, Rgb},
+    contrast::{AnsiPalette, Contrast, ContrastTarget},
+    style::Style,
+    theme::{Theme, DEFAULT_ROLE},
+};
+
+///The kind of problem a [Diagnostic] reports
+#[derive(Clone, Debug, PartialEq)]
+pub enum LintKind {
+    ///The foreground and background are the same color, so text is invisible
+    SameColors,
+    ///`hidden` is set along with other attributes, which have no effect on hidden text
+    HiddenWithAttributes,
+    ///`bold` and `dimmed` are both set. Many terminals use the same intensity setting for both,
+    ///so only one of them is shown.
+    BoldAndDimmed,
+    ///The contrast between the foreground and background is below the target
+    LowContrast(Contrast),
+    ///The foreground and background are different colors, but both become this [Ansi] color in a
+    ///16 color terminal
+    CollapsedColors(Ansi),
+    ///The role is not one of the known roles, or a parent of one
+    UnknownRole,
+}
+
+///A problem found by a lint
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagnostic {
+    ///The role the problem was found in. Empty when linting a single [Style].
+    pub key: String,
+    ///The problem
+    pub kind: LintKind,
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !self.key.is_empty() {
+            write!(f, "{}: ", self.key)?;
+        }
+        match &self.kind {
+            LintKind::SameColors => write!(f, "foreground and background are the same color"),
+            LintKind::HiddenWithAttributes => {
+                write!(f, "hidden is combined with other attributes")
+            }
+            LintKind::BoldAndDimmed => write!(f, "bold and dimmed are both set"),
+            LintKind::LowContrast(contrast) => write!(
+                f,
+                "low contrast (WCAG {:.2}, APCA Lc {:.1})",
+                contrast.wcag, contrast.apca
+            ),
+            LintKind::CollapsedColors(ansi) => write!(
+                f,
+                "foreground and background are both {:?} in 16 color terminals",
+                ansi
+            ),
+            LintKind::UnknownRole => write!(f, "unknown role"),
+        }
+    }
+}
+
+///Options for [Style::lint] and [Theme::lint]
+#[derive(Clone, Debug)]
+pub struct LintOptions {
+    ///The palette terminal colors are resolved with
+    pub palette: AnsiPalette,
+    ///The minimum contrast of styles with a foreground or background set. [None] disables the
+    ///contrast check.
+    pub contrast: Option<ContrastTarget>,
+    ///The roles an application uses. Roles which are not in this list, or parents of roles in it,
+    ///are reported as unknown. [None] disables the check.
+    pub known_roles: Option<Vec<String>>,
+    ///The metric used to find the [Ansi] color a color is shown as in 16 color terminals
+    pub metric: DistanceMetric,
+}
+
+impl Default for LintOptions {
+    ///The xterm palette, a WCAG AA (4.5) contrast target, no known roles and the default
+    ///[DistanceMetric]
+    fn default() -> Self {
+        Self {
+            palette: AnsiPalette::default(),
+            contrast: Some(ContrastTarget::Wcag(4.5)),
+            known_roles: None,
+            metric: DistanceMetric::default(),
+        }
+    }
+}
+
+impl LintOptions {
+    ///Set the known roles
+    pub fn known_roles<S: ToString>(mut self, roles: &[S]) -> Self {
+        self.known_roles = Some(roles.iter().map(|role| role.to_string()).collect());
+        self
+    }
+
+    ///Set the contrast target, or disable the contrast check with [None]
+    pub fn contrast(mut self, target: Option<ContrastTarget>) -> Self {
+        self.contrast = target;
+        self
+    }
+
+    ///Set the metric used to match colors to [Ansi] colors
+    pub fn metric(mut self, metric: DistanceMetric) -> Self {
+        self.metric = metric;
+        self
+    }
+
+    ///Set the palette terminal colors are resolved with
+    pub fn palette(mut self, palette: AnsiPalette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    fn is_known(&self, role: &str) -> bool {
+        match &self.known_roles {
+            None => true,
+            Some(known) => {
+                role == DEFAULT_ROLE
+                    || known.iter().any(|known| {
+                        known == role
+                            || known
+                                .strip_prefix(role)
+                                .is_some_and(|rest| rest.starts_with('.'))
+                    })
+            }
+        }
+    }
+}
+
+///Get the [Ansi] color a color is shown as in a 16 color terminal
+fn to_ansi(color: &Color, options: &LintOptions) -> Option<Ansi> {
+    let rgb = match color {
+        Color::Ansi(ansi) => return Some(*ansi),
+        Color::Fixed(fixed) if fixed.0 < 16 => return Ansi::from_index(fixed.0),
+        Color::Fixed(fixed) => fixed.to_rgb(),
+        Color::Rgb(_) => color.to_rgb()?,
+    };
+    let candidates = options.palette.colors.into_iter().enumerate();
+    let (index, _) = options.metric.nearest(rgb.get_rgb(), candidates)?;
+    Ansi::from_index(index as u8)
+}
+
+fn lint_attributes(style: &Style) -> Vec<LintKind> {
+    let attributes = &style.attributes;
+    let mut problems = Vec::new();
+    let set = attributes.list().iter().filter(|a| a.0).count();
+    if attributes.hidden.0 && set > 1 {
+        problems.push(LintKind::HiddenWithAttributes);
+    }
+    if attributes.bold.0 && attributes.dimmed.0 {
+        problems.push(LintKind::BoldAndDimmed);
+    }
+    problems
+}
+
+fn lint_colors(style: &Style, options: &LintOptions) -> Vec<LintKind> {
+    let mut problems = Vec::new();
+    if style.fg.is_none() && style.bg.is_none() {
+        return problems;
+    }
+    let palette = &options.palette;
+    let (fg, bg): (Rgb, Rgb) = (
+        palette.foreground(style.fg.as_ref()),
+        palette.background(style.bg.as_ref()),
+    );
+    if fg.get_rgb() == bg.get_rgb() {
+        problems.push(LintKind::SameColors);
+    } else if let (Some(fg), Some(bg)) = (&style.fg, &style.bg) {
+        match (to_ansi(fg, options), to_ansi(bg, options)) {
+            (Some(fg), Some(bg)) if fg == bg && fg != Ansi::Default => {
+                problems.push(LintKind::CollapsedColors(fg))
+            }
+            _ => {}
+        }
+    }
+    if let Some(target) = options.contrast {
+        let contrast = style.contrast_with(palette);
+        if !target.is_met(&contrast) && !problems.contains(&LintKind::SameColors) {
+            problems.push(LintKind::LowContrast(contrast));
+        }
+    }
+    problems
+}
+
+fn diagnostics(key: &str, problems: Vec<LintKind>) -> impl Iterator<Item = Diagnostic> + '_ {
+    problems.into_iter().map(move |kind| Diagnostic {
+        key: key.to_string(),
+        kind,
+    })
+}
+
+impl Style {
+    ///Check the style for problems. Known roles are not used.
+    pub fn lint(&self, options: &LintOptions) -> Vec<Diagnostic> {
+        let mut problems = lint_attributes(self);
+        problems.extend(lint_colors(self, options));
+        diagnostics("", problems).collect()
+    }
+}
+
+impl Theme {
+    ///Check every role of the theme for problems. Attributes are checked on the style defined
+    ///for each role, and colors on the resolved style (see [Theme::resolve]), so a problem with
+    ///attributes is only reported on the role which sets them.
+    pub fn lint(&self, options: &LintOptions) -> Vec<Diagnostic> {
+        let mut found = Vec::new();
+        for (role, style) in self.roles() {
+            if !options.is_known(role) {
+                found.push(Diagnostic {
+                    key: role.to_string(),
+                    kind: LintKind::UnknownRole,
+                });
+            }
+            found.extend(diagnostics(role, lint_attributes(style)));
+            found.extend(diagnostics(role, lint_colors(&self.resolve(role), options)));
+        }
+        found
+    }
+}
+
+#[cfg(test)]
+mod lint_tests {
+    use super::*;
+    use crate::{color::Fixed, style::Attributes};
+
+    fn kinds(style: Style) -> Vec<LintKind> {
+        style
+            .lint(&LintOptions::default())
+            .into_iter()
+            .map(|d| d.kind)
+            .collect()
+    }
+
+    #[test]
+    fn test_lint_style() {
+        assert_eq!(kinds(Style::default()), []);
+        assert_eq!(
+            kinds(Style::default().fg(Ansi::Red).bg(Fixed::new().code(1))),
+            [LintKind::SameColors]
+        );
+        assert_eq!(
+            kinds(Style::default().attributes(Attributes::new().hidden().bold().dim())),
+            [LintKind::HiddenWithAttributes, LintKind::BoldAndDimmed]
+        );
+        assert_eq!(
+            kinds(
+                Style::default()
+                    .fg(Rgb::new().rgb([250, 250, 250]))
+                    .bg(Ansi::LightGray)
+            ),
+            [
+                LintKind::CollapsedColors(Ansi::LightGray),
+                LintKind::LowContrast(
+                    Style::default()
+                        .fg(Rgb::new().rgb([250, 250, 250]))
+                        .bg(Ansi::LightGray)
+                        .contrast()
+                )
+            ]
+        );
+        let low = Style::default().fg(Ansi::Blue);
+        assert!(matches!(kinds(low)[..], [LintKind::LowContrast(_)]));
+        let options = LintOptions::default().contrast(None);
+        assert!(Style::default().fg(Ansi::Blue).lint(&options).is_empty());
+    }
+
+    #[test]
+    fn test_lint_theme() {
+        let theme = Theme::new()
+            .role("default", Style::default().fg(Ansi::White))
+            .role(
+                "diff",
+                Style::default().attributes(Attributes::new().hidden().italic()),
+            )
+            .role("diff.added", Style::default().fg(Ansi::BrightGreen))
+            .role("dif.removed", Style::default().fg(Ansi::BrightRed));
+        let options = LintOptions::default().known_roles(&["diff.added", "diff.removed"]);
+        let diagnostics = theme.lint(&options);
+        assert_eq!(
+            diagnostics,
+            [
+                Diagnostic {
+                    key: "dif.removed".into(),
+                    kind: LintKind::UnknownRole
+                },
+                Diagnostic {
+                    key: "diff".into(),
+                    kind: LintKind::HiddenWithAttributes
+                },
+            ]
+        );
+        assert_eq!(diagnostics[0].to_string(), "dif.removed: unknown role");
+    }
+}
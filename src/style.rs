@@ -9,6 +9,8 @@ mod attributes;
 
 pub use attributes::Attribute;
 pub use attributes::Attributes;
+#[cfg(feature = "serde")]
+pub(crate) use attributes::ExplicitAttributes;
 
 use crate::color::{Color, ColorValue};
 
@@ -21,6 +23,7 @@ pub struct Style {
     ///Text background color. Default if set to [None]
     pub bg: Option<Color>,
     ///Text attributes such as bold and underline
+    #[cfg_attr(feature = "serde", serde(default))]
     pub attributes: Attributes,
 }
 
@@ -55,6 +58,20 @@ impl Style {
         self.attributes = attributes;
         self
     }
+
+    ///Fill in the unset parts of the style from a parent style. Colors set to [None] and
+    ///attributes which are not set (see [Attributes::inherit]) are taken from the parent, so a
+    ///child can turn off an attribute of its parent with a setter like [Attributes::set_bold].
+    pub fn inherit(mut self, parent: &Style) -> Self {
+        if self.fg.is_none() {
+            self.fg = parent.fg.clone();
+        }
+        if self.bg.is_none() {
+            self.bg = parent.bg.clone();
+        }
+        self.attributes = self.attributes.inherit(&parent.attributes);
+        self
+    }
 }
 
 impl Display for Style {
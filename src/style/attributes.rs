@@ -61,7 +61,11 @@ impl<'de> serde::Deserialize<'de> for Attribute {
     }
 }
 
-#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(from = "ExplicitAttributes")
+)]
 #[derive(Debug, Clone, PartialEq, Default)]
 ///Stores text attributes like bold, strikethrough, etc.
 pub struct Attributes {
@@ -81,8 +85,19 @@ pub struct Attributes {
     pub hidden: Attribute,
     ///Whether text is struck through
     pub strikethrough: Attribute,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    explicit: u8,
 }
 
+const BOLD: u8 = 1;
+const DIMMED: u8 = 1 << 1;
+const ITALIC: u8 = 1 << 2;
+const UNDERLINE: u8 = 1 << 3;
+const BLINK: u8 = 1 << 4;
+const REVERSE: u8 = 1 << 5;
+const HIDDEN: u8 = 1 << 6;
+const STRIKETHROUGH: u8 = 1 << 7;
+
 impl Attributes {
     ///Create an empty attributes object
     pub fn new() -> Attributes {
@@ -90,14 +105,7 @@ impl Attributes {
     }
     ///Reset all attributes to false
     pub fn reset(&mut self) {
-        self.bold = Attribute::new(false);
-        self.dimmed = Attribute::new(false);
-        self.italic = Attribute::new(false);
-        self.underline = Attribute::new(false);
-        self.blink = Attribute::new(false);
-        self.reverse = Attribute::new(false);
-        self.hidden = Attribute::new(false);
-        self.strikethrough = Attribute::new(false);
+        *self = Attributes::default();
     }
     ///Returns whether all attributes are false
     pub fn is_plain(&self) -> bool {
@@ -110,6 +118,27 @@ impl Attributes {
             && !self.hidden.0
             && !self.strikethrough.0
     }
+    ///Fill in the unset attributes from a parent. Attributes which are enabled, or were
+    ///disabled through a setter such as [Attributes::set_bold], are kept and the rest are taken
+    ///from the parent.
+    pub fn inherit(&self, parent: &Attributes) -> Attributes {
+        let set = self.set_mask();
+        let pick = |flag: u8, child: Attribute, parent: Attribute| match set & flag != 0 {
+            true => child,
+            false => parent,
+        };
+        Attributes {
+            bold: pick(BOLD, self.bold, parent.bold),
+            dimmed: pick(DIMMED, self.dimmed, parent.dimmed),
+            italic: pick(ITALIC, self.italic, parent.italic),
+            underline: pick(UNDERLINE, self.underline, parent.underline),
+            blink: pick(BLINK, self.blink, parent.blink),
+            reverse: pick(REVERSE, self.reverse, parent.reverse),
+            hidden: pick(HIDDEN, self.hidden, parent.hidden),
+            strikethrough: pick(STRIKETHROUGH, self.strikethrough, parent.strikethrough),
+            explicit: self.explicit | parent.explicit,
+        }
+    }
     ///Returns a list of all the attributes
     pub fn list(&self) -> Vec<&Attribute> {
         vec![
@@ -123,44 +152,171 @@ impl Attributes {
             &self.strikethrough,
         ]
     }
+    ///Flags of the attributes which are enabled or were set explicitly
+    fn set_mask(&self) -> u8 {
+        self.list()
+            .into_iter()
+            .enumerate()
+            .filter(|(_, attribute)| attribute.0)
+            .fold(self.explicit, |mask, (index, _)| mask | 1 << index)
+    }
+    ///The attributes which are enabled or were set explicitly, leaving the rest unset
+    #[cfg(feature = "serde")]
+    pub(crate) fn explicit(&self) -> ExplicitAttributes {
+        let set = self.set_mask();
+        let get = |flag: u8, attribute: Attribute| (set & flag != 0).then_some(attribute);
+        ExplicitAttributes {
+            bold: get(BOLD, self.bold),
+            dimmed: get(DIMMED, self.dimmed),
+            italic: get(ITALIC, self.italic),
+            underline: get(UNDERLINE, self.underline),
+            blink: get(BLINK, self.blink),
+            reverse: get(REVERSE, self.reverse),
+            hidden: get(HIDDEN, self.hidden),
+            strikethrough: get(STRIKETHROUGH, self.strikethrough),
+        }
+    }
+
+    ///Set the bold attribute, overriding the bold attribute of a parent style
+    pub fn set_bold(&mut self, value: bool) {
+        self.bold.0 = value;
+        self.explicit |= BOLD;
+    }
+    ///Set the dimmed attribute, overriding the dimmed attribute of a parent style
+    pub fn set_dimmed(&mut self, value: bool) {
+        self.dimmed.0 = value;
+        self.explicit |= DIMMED;
+    }
+    ///Set the italic attribute, overriding the italic attribute of a parent style
+    pub fn set_italic(&mut self, value: bool) {
+        self.italic.0 = value;
+        self.explicit |= ITALIC;
+    }
+    ///Set the underline attribute, overriding the underline attribute of a parent style
+    pub fn set_underline(&mut self, value: bool) {
+        self.underline.0 = value;
+        self.explicit |= UNDERLINE;
+    }
+    ///Set the blink attribute, overriding the blink attribute of a parent style
+    pub fn set_blink(&mut self, value: bool) {
+        self.blink.0 = value;
+        self.explicit |= BLINK;
+    }
+    ///Set the reverse attribute, overriding the reverse attribute of a parent style
+    pub fn set_reverse(&mut self, value: bool) {
+        self.reverse.0 = value;
+        self.explicit |= REVERSE;
+    }
+    ///Set the hidden attribute, overriding the hidden attribute of a parent style
+    pub fn set_hidden(&mut self, value: bool) {
+        self.hidden.0 = value;
+        self.explicit |= HIDDEN;
+    }
+    ///Set the strikethrough attribute, overriding the strikethrough attribute of a parent style
+    pub fn set_strikethrough(&mut self, value: bool) {
+        self.strikethrough.0 = value;
+        self.explicit |= STRIKETHROUGH;
+    }
 
     ///Set the bold attribute to true
     pub fn bold(mut self) -> Self {
-        self.bold = self.bold.on();
+        self.set_bold(true);
         self
     }
     ///Set the dimmed attribute to true
     pub fn dim(mut self) -> Self {
-        self.dimmed = self.dimmed.on();
+        self.set_dimmed(true);
         self
     }
     ///Set the italic attribute to true
     pub fn italic(mut self) -> Self {
-        self.italic = self.italic.on();
+        self.set_italic(true);
         self
     }
     ///Set the underline attribute to true
     pub fn underline(mut self) -> Self {
-        self.underline = self.underline.on();
+        self.set_underline(true);
         self
     }
     ///Set the blink attribute to true
     pub fn blink(mut self) -> Self {
-        self.blink = self.blink.on();
+        self.set_blink(true);
         self
     }
     ///Set the reverse attribute to true
     pub fn reverse(mut self) -> Self {
-        self.reverse = self.reverse.on();
+        self.set_reverse(true);
+        self
+    }
+    ///Set the hidden attribute to true
+    pub fn hidden(mut self) -> Self {
+        self.set_hidden(true);
         self
     }
     ///Set the strikethrough attribute to true
     pub fn strikethrough(mut self) -> Self {
-        self.strikethrough = self.strikethrough.on();
+        self.set_strikethrough(true);
         self
     }
 }
 
+///Attributes where each one may be left unset, used to read and write only the attributes
+///which were given
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub(crate) struct ExplicitAttributes {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bold: Option<Attribute>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dimmed: Option<Attribute>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    italic: Option<Attribute>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    underline: Option<Attribute>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    blink: Option<Attribute>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reverse: Option<Attribute>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hidden: Option<Attribute>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    strikethrough: Option<Attribute>,
+}
+
+#[cfg(feature = "serde")]
+impl From<ExplicitAttributes> for Attributes {
+    fn from(value: ExplicitAttributes) -> Self {
+        let mut attributes = Attributes::new();
+        let fields = [
+            value.bold,
+            value.dimmed,
+            value.italic,
+            value.underline,
+            value.blink,
+            value.reverse,
+            value.hidden,
+            value.strikethrough,
+        ];
+        let setters = [
+            Attributes::set_bold,
+            Attributes::set_dimmed,
+            Attributes::set_italic,
+            Attributes::set_underline,
+            Attributes::set_blink,
+            Attributes::set_reverse,
+            Attributes::set_hidden,
+            Attributes::set_strikethrough,
+        ];
+        for (field, set) in fields.into_iter().zip(setters) {
+            if let Some(attribute) = field {
+                set(&mut attributes, attribute.0);
+            }
+        }
+        attributes
+    }
+}
+
 impl Display for Attributes {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         fn display_if_true(
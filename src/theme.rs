@@ -0,0 +1,503 @@
+/*!
+[Theme] maps role names to [Style]s.
+
+Roles are hierarchical, with each level of a role separated by a dot. When a role is resolved, any
+part of its style which is unset is inherited from its parents, and finally from the `default`
+role:
+```rust
+use antsee::{Ansi, Attributes, Style, Theme};
+
+let theme = Theme::new()
+    .role("default", Style::default().fg(Ansi::White))
+    .role("diff", Style::default().attributes(Attributes::new().bold()))
+    .role("diff.added", Style::default().fg(Ansi::Green));
+
+let style = theme.resolve("diff.added.line");
+assert_eq!(style.fg, Some(Ansi::Green.into()));
+assert!(style.attributes.bold.0);
+assert_eq!(theme.resolve("unknown").fg, Some(Ansi::White.into()));
+```
+
+# Palette
+A theme can hold a [Palette] of named colors. Any color in the theme can reference a palette
+entry with `$name` or `palette(name)`. Roles keep their references, so they serialize unchanged,
+and [Theme::resolve] returns the referenced colors. References are checked with
+[Theme::check_references], which happens automatically when a theme is deserialized.
+
+Colors can also be derived from other colors with expressions such as `darken($accent, 10%)` or
+`mix($accent, css(gray), 50%)`. These are evaluated when references are resolved, and also keep
+the expression as their source.
+
+# Serde
+A theme serializes as a map of role names to styles, with the palette under the reserved
+`palette` key. When deserializing, roles may also be nested inside their parents, which is how
+formats like TOML represent unquoted dotted keys:
+```toml
+[palette]
+accent = "#c678dd"
+
+[diff]
+attributes = { bold = true }
+
+[diff.added]
+fg = "$accent"
+```
+*/
+
+use std::collections::BTreeMap;
+
+use crate::{
+    color::Color,
+    contrast::{AnsiPalette, ContrastTarget},
+    style::Style,
+};
+
+mod palette;
+
+pub use palette::{Palette, PaletteError};
+
+///The role every other role inherits from
+pub const DEFAULT_ROLE: &str = "default";
+
+///A set of named roles mapped to [Style]s, with a [Palette] of colors they can reference
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Theme {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Palette::is_empty"))]
+    palette: Palette,
+    #[cfg_attr(feature = "serde", serde(flatten, serialize_with = "serialize_roles"))]
+    roles: BTreeMap<String, Style>,
+}
+
+impl Theme {
+    ///Create an empty theme
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///Set the style of a role
+    pub fn role(mut self, role: &str, style: Style) -> Self {
+        self.set(role, style);
+        self
+    }
+
+    ///Set the style of a role, returning the previous style if there was one
+    pub fn set(&mut self, role: &str, style: Style) -> Option<Style> {
+        self.roles.insert(role.to_string(), style)
+    }
+
+    ///Get the style defined for exactly this role, without inheritance
+    pub fn get(&self, role: &str) -> Option<&Style> {
+        self.roles.get(role)
+    }
+
+    ///Get a mutable reference to the style defined for exactly this role
+    pub fn get_mut(&mut self, role: &str) -> Option<&mut Style> {
+        self.roles.get_mut(role)
+    }
+
+    ///Remove a role from the theme
+    pub fn remove(&mut self, role: &str) -> Option<Style> {
+        self.roles.remove(role)
+    }
+
+    ///Iterate over the defined roles and their styles, ordered by role name
+    pub fn roles(&self) -> impl Iterator<Item = (&str, &Style)> {
+        self.roles
+            .iter()
+            .map(|(role, style)| (role.as_str(), style))
+    }
+
+    ///Set the palette of the theme
+    pub fn palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    ///Get the palette of the theme
+    pub fn get_palette(&self) -> &Palette {
+        &self.palette
+    }
+
+    ///Get a mutable reference to the palette of the theme
+    pub fn get_palette_mut(&mut self) -> &mut Palette {
+        &mut self.palette
+    }
+
+    ///Check that every palette reference and color expression in the palette and the roles of
+    ///the theme resolves
+    pub fn check_references(&self) -> Result<(), PaletteError> {
+        self.palette.resolve_entries()?;
+        for style in self.roles.values() {
+            for color in style.fg.iter().chain(&style.bg) {
+                self.palette.resolve(color)?;
+            }
+        }
+        Ok(())
+    }
+
+    ///Get the roles a role inherits from, from the most to the least specific. The role itself is
+    ///the first element and [DEFAULT_ROLE] is the last.
+    pub fn lineage(role: &str) -> Vec<&str> {
+        let mut lineage: Vec<&str> = role
+            .char_indices()
+            .filter(|(_, c)| *c == '.')
+            .map(|(i, _)| &role[..i])
+            .rev()
+            .collect();
+        lineage.insert(0, role);
+        if role != DEFAULT_ROLE {
+            lineage.push(DEFAULT_ROLE);
+        }
+        lineage
+    }
+
+    ///Resolve a role to a full style, inheriting unset colors and attributes from its parents.
+    ///Roles which are not defined resolve to their closest defined parent. Palette references are
+    ///replaced by the colors they reference, and references which don't resolve are kept.
+    pub fn resolve(&self, role: &str) -> Style {
+        let mut style = Self::lineage(role)
+            .iter()
+            .filter_map(|role| self.roles.get(*role))
+            .fold(Style::default(), |style, parent| style.inherit(parent));
+        let resolve = |color: Color| self.palette.resolve(&color).unwrap_or(color);
+        style.fg = style.fg.map(resolve);
+        style.bg = style.bg.map(resolve);
+        style
+    }
+
+    ///Adjust the foreground of every role whose resolved style doesn't meet a contrast target,
+    ///using [Style::ensure_contrast_with]. Parents are repaired before their children, so
+    ///children which inherit a repaired color are not changed. Returns the roles which were
+    ///changed.
+    pub fn repair_contrast(
+        &mut self,
+        target: ContrastTarget,
+        palette: &AnsiPalette,
+    ) -> Vec<String> {
+        let mut roles: Vec<String> = self.roles.keys().cloned().collect();
+        roles.sort_by_key(|role| (role != DEFAULT_ROLE, role.matches('.').count()));
+        let mut changed = Vec::new();
+        for role in roles {
+            let mut resolved = self.resolve(&role);
+            if resolved.ensure_contrast_with(target, palette) {
+                if let Some(style) = self.roles.get_mut(&role) {
+                    //Only the adjusted color is written, so the role keeps inheriting the other
+                    if resolved.attributes.reverse.0 {
+                        style.bg = resolved.bg;
+                    } else {
+                        style.fg = resolved.fg;
+                    }
+                }
+                changed.push(role);
+            }
+        }
+        changed.sort();
+        changed
+    }
+}
+
+impl FromIterator<(String, Style)> for Theme {
+    fn from_iter<T: IntoIterator<Item = (String, Style)>>(iter: T) -> Self {
+        Self {
+            palette: Palette::default(),
+            roles: iter.into_iter().collect(),
+        }
+    }
+}
+
+///A role as it is written, with only the attributes it sets so they still override its parents
+///when read back
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct RoleOutput<'a> {
+    fg: &'a Option<Color>,
+    bg: &'a Option<Color>,
+    attributes: crate::style::ExplicitAttributes,
+}
+
+#[cfg(feature = "serde")]
+fn serialize_roles<S>(roles: &BTreeMap<String, Style>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.collect_map(roles.iter().map(|(role, style)| {
+        let output = RoleOutput {
+            fg: &style.fg,
+            bg: &style.bg,
+            attributes: style.attributes.explicit(),
+        };
+        (role, output)
+    }))
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RoleEntry {
+    fg: Option<Color>,
+    bg: Option<Color>,
+    attributes: Option<crate::style::Attributes>,
+    #[serde(flatten)]
+    children: BTreeMap<String, RoleEntry>,
+}
+
+#[cfg(feature = "serde")]
+impl RoleEntry {
+    fn flatten_into(self, role: String, roles: &mut BTreeMap<String, Style>) {
+        for (child, entry) in self.children {
+            entry.flatten_into(format!("{}.{}", role, child), roles);
+        }
+        if self.fg.is_some() || self.bg.is_some() || self.attributes.is_some() {
+            roles.insert(
+                role,
+                Style {
+                    fg: self.fg,
+                    bg: self.bg,
+                    attributes: self.attributes.unwrap_or_default(),
+                },
+            );
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct ThemeEntries {
+    #[serde(default)]
+    palette: Palette,
+    #[serde(flatten)]
+    roles: BTreeMap<String, RoleEntry>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Theme {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let entries = ThemeEntries::deserialize(deserializer)?;
+        let mut roles = BTreeMap::new();
+        for (role, entry) in entries.roles {
+            entry.flatten_into(role, &mut roles);
+        }
+        let theme = Self {
+            palette: entries.palette,
+            roles,
+        };
+        theme.check_references().map_err(serde::de::Error::custom)?;
+        Ok(theme)
+    }
+}
+
+#[cfg(test)]
+mod theme_tests {
+    use super::*;
+    use crate::{
+        color::{Ansi, Fixed},
+        style::Attributes,
+    };
+
+    #[test]
+    fn test_lineage() {
+        assert_eq!(
+            Theme::lineage("diff.added.line"),
+            ["diff.added.line", "diff.added", "diff", "default"]
+        );
+        assert_eq!(Theme::lineage("default"), ["default"]);
+    }
+
+    #[test]
+    fn test_resolve_overrides_parent() {
+        let theme = Theme::new()
+            .role("diff", Style::default().fg(Ansi::Red).bg(Ansi::Black))
+            .role(
+                "diff.added",
+                Style::default()
+                    .fg(Ansi::Green)
+                    .attributes(Attributes::new().italic()),
+            );
+        let style = theme.resolve("diff.added");
+        assert_eq!(style.fg, Some(Ansi::Green.into()));
+        assert_eq!(style.bg, Some(Ansi::Black.into()));
+        assert_eq!(style.attributes, Attributes::new().italic());
+        assert_eq!(theme.resolve("diff.removed").fg, Some(Ansi::Red.into()));
+        assert_eq!(theme.resolve("status").fg, None);
+    }
+
+    #[test]
+    fn test_resolve_attribute_off() {
+        let mut plain = Attributes::new().italic();
+        plain.set_bold(false);
+        let theme = Theme::new()
+            .role(
+                "heading",
+                Style::default().attributes(Attributes::new().bold().underline()),
+            )
+            .role("heading.quiet", Style::default().attributes(plain.clone()));
+        let style = theme.resolve("heading.quiet");
+        assert_eq!(style.attributes, plain.underline());
+        assert!(!style.attributes.bold.0);
+        assert!(theme.resolve("heading.loud").attributes.bold.0);
+    }
+
+    #[test]
+    fn test_repair_contrast() {
+        let mut theme = Theme::new()
+            .role("default", Style::default().fg(Ansi::White))
+            .role("comment", Style::default().fg(Fixed::new().code(236)))
+            .role(
+                "comment.doc",
+                Style::default().attributes(Attributes::new().italic()),
+            )
+            .role("title", Style::default().fg(Ansi::BrightYellow));
+        let target = ContrastTarget::Wcag(4.5);
+        let changed = theme.repair_contrast(target, &AnsiPalette::default());
+        assert_eq!(changed, ["comment"]);
+        assert!(target.is_met(&theme.resolve("comment.doc").contrast()));
+        assert_eq!(theme.get("comment.doc").unwrap().fg, None);
+        assert!(theme
+            .repair_contrast(target, &AnsiPalette::default())
+            .is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_nested_roles() {
+        let theme: Theme = toml::from_str(
+            r#"
+            default = { fg = "White" }
+            "diff.removed" = { fg = "Red" }
+
+            [diff]
+            attributes = { bold = true }
+
+            [diff.added]
+            fg = "Green"
+
+            [diff.removed.line]
+            attributes = { bold = false }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            theme.roles().map(|(role, _)| role).collect::<Vec<_>>(),
+            [
+                "default",
+                "diff",
+                "diff.added",
+                "diff.removed",
+                "diff.removed.line"
+            ]
+        );
+        let style = theme.resolve("diff.added.line");
+        assert_eq!(style.fg, Some(Ansi::Green.into()));
+        assert!(style.attributes.bold.0);
+        assert!(!theme.resolve("diff.removed.line").attributes.bold.0);
+
+        let serialized = serde_json::to_string(&theme).unwrap();
+        assert!(serialized.starts_with(r#"{"default":{"fg":"White","bg":null,"attributes":{}}"#));
+        let reparsed: Theme = serde_json::from_str(&serialized).unwrap();
+        assert!(reparsed.resolve("diff.added").attributes.bold.0);
+        assert!(!reparsed.resolve("diff.removed.line").attributes.bold.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_palette_references() {
+        let source = r##"
+            [palette]
+            accent = "#c678dd"
+            highlight = "palette(accent)"
+            warning = "Yellow"
+
+            [title]
+            fg = "$highlight"
+            bg = "$warning"
+            "##;
+        let theme: Theme = toml::from_str(source).unwrap();
+        let title = theme.resolve("title");
+        assert_eq!(
+            title.fg.as_ref().unwrap().as_rgb().unwrap().get_rgb(),
+            [198, 120, 221]
+        );
+        assert!(title.fg.as_ref().unwrap().is_resolved());
+        assert_eq!(title.bg, Some(Ansi::Yellow.into()));
+        assert_eq!(
+            theme
+                .get("title")
+                .unwrap()
+                .bg
+                .as_ref()
+                .unwrap()
+                .palette_reference(),
+            Some("warning")
+        );
+
+        let serialized = toml::to_string(&theme).unwrap();
+        assert!(serialized.contains(r#"highlight = "palette(accent)""#));
+        assert!(serialized.contains(r#"fg = "$highlight""#));
+        assert!(serialized.contains(r#"bg = "$warning""#));
+
+        let plain = "$plain".parse::<crate::color::Rgb>().unwrap();
+        let theme = Theme::new()
+            .palette(Palette::new().color("plain", Ansi::Default))
+            .role("body", Style::default().fg(plain));
+        assert_eq!(theme.resolve("body").fg, Some(Ansi::Default.into()));
+        let serialized = serde_json::to_string(&theme).unwrap();
+        assert!(serialized.contains(r#""body":{"fg":"$plain""#));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_palette_expressions() {
+        let source = r##"
+            [palette]
+            accent = "#c678dd"
+            muted = "mix($accent, css(gray), 50%)"
+
+            [title]
+            fg = "darken($accent, 10%)"
+            bg = "alpha($muted, 20%, #000000)"
+            "##;
+        let theme: Theme = toml::from_str(source).unwrap();
+        let title = theme.resolve("title");
+        let fg = title.fg.as_ref().unwrap().as_rgb().unwrap().get_rgb();
+        assert!(fg
+            .iter()
+            .zip([198, 120, 221])
+            .all(|(c, accent)| *c < accent));
+        let muted = theme.get_palette().get_resolved("muted").unwrap();
+        let muted = muted.to_rgb().unwrap();
+        let bg = title.bg.as_ref().unwrap().as_rgb().unwrap().get_rgb();
+        assert_eq!(bg, muted.get_rgb().map(|c| (c as f64 * 0.2).round() as u8));
+
+        let serialized = toml::to_string(&theme).unwrap();
+        assert!(serialized.contains(r#"muted = "mix($accent, css(gray), 50%)""#));
+        assert!(serialized.contains(r#"fg = "darken($accent, 10%)""#));
+
+        let cycle = toml::from_str::<Theme>(r#"palette = { a = "lighten($a, 10%)" }"#).unwrap_err();
+        assert!(cycle.message().contains("a -> a"));
+        let palette = Palette::new().color("a", Ansi::Default).color(
+            "b",
+            "mix($a, #ffffff)".parse::<crate::color::Rgb>().unwrap(),
+        );
+        assert_eq!(
+            palette.resolve_entries(),
+            Err(PaletteError::InvalidExpression("mix($a, #ffffff)".into()))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_palette_errors() {
+        let undefined = toml::from_str::<Theme>(r#"title = { fg = "$accent" }"#).unwrap_err();
+        assert!(undefined
+            .message()
+            .contains("Undefined palette entry 'accent'"));
+
+        let cycle =
+            toml::from_str::<Theme>(r#"palette = { a = "$b", b = "palette(c)", c = "$a" }"#)
+                .unwrap_err();
+        assert!(cycle.message().contains("a -> b -> c -> a"));
+    }
+}
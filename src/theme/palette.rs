@@ -0,0 +1,167 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use crate::color::{
+    expression::{self, EvalError},
+    Color, ColorSource, Rgb,
+};
+
+///This enum defines errors that may be encountered when resolving palette references
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaletteError {
+    ///A reference names an entry which is not in the palette
+    Undefined(String),
+    ///Palette entries reference each other in a cycle. Contains the names in the cycle, starting
+    ///and ending with the same name.
+    Cycle(Vec<String>),
+    ///A color expression is invalid, or uses a color without an RGB value such as
+    ///[Ansi::Default]. Contains the expression.
+    InvalidExpression(String),
+}
+
+impl Display for PaletteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Undefined(name) => write!(f, "Undefined palette entry '{}'", name),
+            Self::Cycle(names) => write!(f, "Palette reference cycle: {}", names.join(" -> ")),
+            Self::InvalidExpression(expression) => {
+                write!(f, "Invalid color expression '{}'", expression)
+            }
+        }
+    }
+}
+
+///Named colors which can be referenced from anywhere a [Color] is accepted with `$name` or
+///`palette(name)`
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Palette {
+    colors: BTreeMap<String, Color>,
+}
+
+impl Palette {
+    ///Create an empty palette
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///Add a named color to the palette
+    pub fn color<C: Into<Color>>(mut self, name: &str, color: C) -> Self {
+        self.set(name, color);
+        self
+    }
+
+    ///Add a named color to the palette, returning the previous color if there was one
+    pub fn set<C: Into<Color>>(&mut self, name: &str, color: C) -> Option<Color> {
+        self.colors.insert(name.to_string(), color.into())
+    }
+
+    ///Get a color by name, as it is stored (references are not resolved)
+    pub fn get(&self, name: &str) -> Option<&Color> {
+        self.colors.get(name)
+    }
+
+    ///Remove a color from the palette
+    pub fn remove(&mut self, name: &str) -> Option<Color> {
+        self.colors.remove(name)
+    }
+
+    ///Iterate over the names and colors in the palette, ordered by name
+    pub fn colors(&self) -> impl Iterator<Item = (&str, &Color)> {
+        self.colors
+            .iter()
+            .map(|(name, color)| (name.as_str(), color))
+    }
+
+    ///Returns whether the palette has no colors
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    ///Get the number of colors in the palette
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    fn lookup(&self, name: &str, chain: &mut Vec<String>) -> Result<Color, PaletteError> {
+        if chain.iter().any(|n| n == name) {
+            chain.push(name.to_string());
+            let start = chain.iter().position(|n| n == name).unwrap_or_default();
+            return Err(PaletteError::Cycle(chain[start..].to_vec()));
+        }
+        let color = self
+            .colors
+            .get(name)
+            .ok_or_else(|| PaletteError::Undefined(name.to_string()))?;
+        match color.palette_reference() {
+            Some(reference) => {
+                chain.push(name.to_string());
+                let resolved = self.lookup(reference, chain)?;
+                chain.pop();
+                Ok(resolved)
+            }
+            None => match color.expression() {
+                Some(expression) => {
+                    chain.push(name.to_string());
+                    let resolved = self.evaluate(expression, chain)?;
+                    chain.pop();
+                    Ok(resolved)
+                }
+                None => Ok(color.clone()),
+            },
+        }
+    }
+
+    ///Evaluate a color expression, looking up its references in the palette. The result keeps the
+    ///expression as its source.
+    fn evaluate(&self, expression: &str, chain: &mut Vec<String>) -> Result<Color, PaletteError> {
+        let invalid = || PaletteError::InvalidExpression(expression.to_string());
+        let value = expression::evaluate(expression, &mut |name| {
+            self.lookup(name, chain)?
+                .to_rgb()
+                .map(|rgb| rgb.get_rgb())
+                .ok_or_else(invalid)
+        })
+        .map_err(|e| match e {
+            EvalError::Invalid(_) => invalid(),
+            EvalError::Lookup(e) => e,
+        })?;
+        let mut rgb = Rgb::new().rgb(value);
+        rgb.set_external_source(expression.to_string());
+        Ok(rgb.into())
+    }
+
+    ///Resolve a color against the palette. If the color is a palette reference, the referenced
+    ///color is returned as it is, so [Ansi](crate::color::Ansi) entries stay [Ansi](crate::color::Ansi).
+    ///Color expressions are evaluated, and keep the expression as their source. Other colors are
+    ///returned unchanged.
+    ///
+    ///The reference itself stays on the color it was written in (e.g. a palette entry or a role of
+    ///a [Theme](crate::Theme)), which is what serializes.
+    pub fn resolve(&self, color: &Color) -> Result<Color, PaletteError> {
+        match color.palette_reference() {
+            Some(reference) => self.lookup(reference, &mut Vec::new()),
+            None => match color.expression() {
+                Some(expression) => self.evaluate(expression, &mut Vec::new()),
+                None => Ok(color.clone()),
+            },
+        }
+    }
+
+    ///Get an entry by name with its references and expressions resolved
+    pub fn get_resolved(&self, name: &str) -> Result<Color, PaletteError> {
+        self.lookup(name, &mut Vec::new())
+    }
+
+    ///Resolve every entry of the palette, returning the resolved colors by name. The palette keeps
+    ///its entries as they were written.
+    pub fn resolve_entries(&self) -> Result<BTreeMap<String, Color>, PaletteError> {
+        self.colors
+            .keys()
+            .map(|name| Ok((name.clone(), self.get_resolved(name)?)))
+            .collect()
+    }
+}
@@ -0,0 +1,187 @@
+/*!
+Color vision deficiency simulation.
+
+[Rgb::simulate] shows how a color appears with a [ColorVisionDeficiency], and
+[Theme::indistinguishable_roles] uses it to find roles whose colors can't be told apart, such as
+error and success colors for someone with deuteranopia:
+```rust
+use antsee::{color::DistanceMetric, contrast::AnsiPalette, Ansi, Style, Theme};
+
+let theme = Theme::new()
+    .role("error", Style::default().fg(Ansi::Red))
+    .role("success", Style::default().fg(Ansi::Green));
+let problems = theme.indistinguishable_roles(
+    &[("error", "success")],
+    15.0,
+    DistanceMetric::Oklab,
+    &AnsiPalette::default(),
+);
+assert!(!problems.is_empty());
+```
+*/
+
+use crate::{
+    color::{
+        space::{from_linear, to_linear},
+        DistanceMetric, Rgb,
+    },
+    contrast::AnsiPalette,
+    theme::Theme,
+};
+
+///A type of color vision deficiency
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ColorVisionDeficiency {
+    ///No red cones (red-green color blindness)
+    Protanopia,
+    ///No green cones (red-green color blindness, the most common type)
+    Deuteranopia,
+    ///No blue cones (blue-yellow color blindness)
+    Tritanopia,
+    ///No color vision, only lightness
+    Achromatopsia,
+}
+
+impl ColorVisionDeficiency {
+    ///Every type of color vision deficiency
+    pub const ALL: [Self; 4] = [
+        Self::Protanopia,
+        Self::Deuteranopia,
+        Self::Tritanopia,
+        Self::Achromatopsia,
+    ];
+
+    ///The matrix applied to linear RGB, from Machado, Oliveira and Fernandes (2009) at full
+    ///severity
+    fn matrix(&self) -> [[f64; 3]; 3] {
+        match self {
+            Self::Protanopia => [
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ],
+            Self::Deuteranopia => [
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ],
+            Self::Tritanopia => [
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ],
+            Self::Achromatopsia => [[0.2126, 0.7152, 0.0722]; 3],
+        }
+    }
+}
+
+impl Rgb {
+    ///Simulate how the color appears with a color vision deficiency. The source of the result is
+    ///inactive.
+    pub fn simulate(&self, deficiency: ColorVisionDeficiency) -> Rgb {
+        let linear = self.get_rgb().map(to_linear);
+        let value = deficiency
+            .matrix()
+            .map(|row| from_linear(row.iter().zip(linear).map(|(m, c)| m * c).sum()));
+        self.derived(value)
+    }
+}
+
+///Two roles whose foreground colors can't be told apart with a color vision deficiency
+#[derive(Clone, Debug, PartialEq)]
+pub struct Indistinguishable {
+    ///The first role of the pair
+    pub first: String,
+    ///The second role of the pair
+    pub second: String,
+    ///The deficiency the colors were simulated with
+    pub deficiency: ColorVisionDeficiency,
+    ///The distance between the simulated colors
+    pub distance: f64,
+}
+
+impl Theme {
+    ///Check that pairs of roles have foreground colors which can be told apart with each
+    ///[ColorVisionDeficiency]. Roles are resolved with [Theme::resolve] and terminal colors with
+    ///the palette.
+    ///
+    ///Returns every pair and deficiency where the distance between the simulated colors is below
+    ///the threshold. Differences below roughly 2 are not noticeable, but colors used to tell
+    ///things apart at a glance should be much further apart.
+    pub fn indistinguishable_roles(
+        &self,
+        pairs: &[(&str, &str)],
+        threshold: f64,
+        metric: DistanceMetric,
+        palette: &AnsiPalette,
+    ) -> Vec<Indistinguishable> {
+        let mut problems = Vec::new();
+        for (first, second) in pairs {
+            let a = palette.foreground(self.resolve(first).fg.as_ref());
+            let b = palette.foreground(self.resolve(second).fg.as_ref());
+            for deficiency in ColorVisionDeficiency::ALL {
+                let distance = metric.distance(&a.simulate(deficiency), &b.simulate(deficiency));
+                if distance < threshold {
+                    problems.push(Indistinguishable {
+                        first: first.to_string(),
+                        second: second.to_string(),
+                        deficiency,
+                        distance,
+                    });
+                }
+            }
+        }
+        problems
+    }
+}
+
+#[cfg(test)]
+mod vision_tests {
+    use super::*;
+    use crate::{color::Ansi, style::Style};
+
+    #[test]
+    fn test_simulate() {
+        let white = Rgb::new().rgb([255; 3]);
+        for deficiency in ColorVisionDeficiency::ALL {
+            let simulated = white.simulate(deficiency).get_rgb();
+            assert!(simulated.iter().all(|c| *c >= 254), "{:?}", deficiency);
+        }
+        let gray = Rgb::new()
+            .rgb([255, 0, 0])
+            .simulate(ColorVisionDeficiency::Achromatopsia)
+            .get_rgb();
+        assert!(gray[0] == gray[1] && gray[1] == gray[2]);
+        let red = Rgb::new().rgb([255, 0, 0]);
+        let green = Rgb::new().rgb([0, 128, 0]);
+        let deuteranopia = ColorVisionDeficiency::Deuteranopia;
+        let metric = DistanceMetric::default();
+        assert!(
+            red.simulate(deuteranopia)
+                .distance(&green.simulate(deuteranopia), metric)
+                < red.distance(&green, metric)
+        );
+    }
+
+    #[test]
+    fn test_indistinguishable_roles() {
+        let theme = Theme::new()
+            .role("error", Style::default().fg(Ansi::Red))
+            .role("success", Style::default().fg(Ansi::Green))
+            .role("warning", Style::default().fg(Ansi::BrightYellow))
+            .role("info", Style::default().fg(Ansi::Blue));
+        let palette = AnsiPalette::default();
+        let problems = theme.indistinguishable_roles(
+            &[("error", "success"), ("warning", "info")],
+            15.0,
+            DistanceMetric::Oklab,
+            &palette,
+        );
+        assert!(problems
+            .iter()
+            .all(|problem| problem.first == "error" && problem.distance < 15.0));
+        assert!(problems
+            .iter()
+            .any(|problem| problem.deficiency == ColorVisionDeficiency::Deuteranopia));
+    }
+}